@@ -1,3 +1,5 @@
+advent_of_code::solution!(2022, 1);
+
 pub fn part_one(input: &str) -> Option<u32> {
     split_in_chunks(input).max()
 }
@@ -19,12 +21,6 @@ fn split_in_chunks<'a>(input: &'a str) -> impl Iterator<Item = u32> + 'a {
     })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,13 +28,13 @@ mod tests {
     #[test]
 
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file(2022, "examples", 1).unwrap();
         assert_eq!(part_one(&input), Some(24000));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file(2022, "examples", 1).unwrap();
         assert_eq!(part_two(&input), Some(45000));
     }
 }
@@ -1,3 +1,5 @@
+advent_of_code::solution!(2022, 2);
+
 pub fn part_one(input: &str) -> Option<u32> {
     Some(
         create_matches(input, Match::from_match_str_day1)
@@ -114,25 +116,19 @@ fn create_matches(input: &str, match_creator: impl Fn(&str) -> Match) -> Vec<Mat
     input.lines().map(match_creator).collect()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file(2022, "examples", 2).unwrap();
         assert_eq!(part_one(&input), Some(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file(2022, "examples", 2).unwrap();
         assert_eq!(part_two(&input), Some(12));
     }
 }
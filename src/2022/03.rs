@@ -1,3 +1,5 @@
+advent_of_code::solution!(2022, 3);
+
 use std::collections::HashSet;
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -51,12 +53,6 @@ fn char_to_value(c: char) -> u32 {
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,13 +67,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file(2022, "examples", 3).unwrap();
         assert_eq!(part_one(&input), Some(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file(2022, "examples", 3).unwrap();
         assert_eq!(part_two(&input), Some(70));
     }
 }
@@ -1,10 +1,12 @@
+advent_of_code::solution!(2022, 4);
+
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(
         create_assigned_section_list(input)
-            .filter(|(section1, section2)| section1.overlap(&section2))
+            .filter(|(section1, section2)| section1.overlap(section2))
             .count() as u32,
     )
 }
@@ -12,7 +14,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     Some(
         create_assigned_section_list(input)
-            .filter(|(section1, section2)| section1.partial_overlap(&section2))
+            .filter(|(section1, section2)| section1.partial_overlap(section2))
             .count() as u32,
     )
 }
@@ -56,16 +58,10 @@ impl FromStr for AssignedSection {
         let (start, end) = s.split_at(s.chars().position(|c| c == '-').unwrap());
         let start = start.parse::<u32>().unwrap();
         let end = end[1..].parse::<u32>().unwrap();
-        Ok(Self(RangeInclusive::new(start, end)))
+        Ok(Self::new(start, end))
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,10 +78,10 @@ mod tests {
             &AssignedSection::new(2, 5)
         ));
 
-        assert_eq!(
-            false,
-            AssignedSection::overlap(&AssignedSection::new(1, 20), &AssignedSection::new(15, 22))
-        );
+        assert!(!AssignedSection::overlap(
+            &AssignedSection::new(1, 20),
+            &AssignedSection::new(15, 22)
+        ));
     }
 
     #[test]
@@ -100,24 +96,21 @@ mod tests {
             &AssignedSection::new(2, 10)
         ));
 
-        assert_eq!(
-            false,
-            AssignedSection::partial_overlap(
-                &AssignedSection::new(1, 5),
-                &AssignedSection::new(6, 22)
-            )
-        );
+        assert!(!AssignedSection::partial_overlap(
+            &AssignedSection::new(1, 5),
+            &AssignedSection::new(6, 22)
+        ));
     }
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file(2022, "examples", 4).unwrap();
         assert_eq!(part_one(&input), Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file(2022, "examples", 4).unwrap();
         assert_eq!(part_two(&input), Some(4));
     }
 }
@@ -1,3 +1,5 @@
+advent_of_code::solution!(2022, 5);
+
 use regex::Regex;
 use std::collections::{BTreeMap, VecDeque};
 
@@ -8,7 +10,6 @@ pub fn part_one(input: &str) -> Option<String> {
         for _ in 1..=movement.size {
             let from = stacks.get_mut(&movement.from).unwrap();
             let element = from.remove();
-            std::mem::drop(from);
             let to = stacks.get_mut(&movement.to).unwrap();
             to.add(element);
         }
@@ -16,8 +17,8 @@ pub fn part_one(input: &str) -> Option<String> {
 
     Some(
         stacks
-            .iter()
-            .map(|(_, stack)| stack.0.front().unwrap())
+            .values()
+            .map(|stack| stack.0.front().unwrap())
             .collect(),
     )
 }
@@ -28,15 +29,14 @@ pub fn part_two(input: &str) -> Option<String> {
     for movement in movements {
         let from = stacks.get_mut(&movement.from).unwrap();
         let elements = from.remove_n_crates(movement.size);
-        std::mem::drop(from);
         let to = stacks.get_mut(&movement.to).unwrap();
         to.add_n_crates(elements);
     }
 
     Some(
         stacks
-            .iter()
-            .map(|(_, stack)| stack.0.front().unwrap())
+            .values()
+            .map(|stack| stack.0.front().unwrap())
             .collect(),
     )
 }
@@ -126,7 +126,7 @@ fn create_stacks(input: &str) -> BTreeMap<char, Stack> {
                 .collect::<Vec<char>>()
                 .chunks(4)
                 .map(|a| {
-                    a.into_iter()
+                    a.iter()
                         .collect::<String>()
                         .trim()
                         .trim_matches('[')
@@ -163,25 +163,19 @@ fn create_movements(input: &str) -> Vec<Movement> {
     input.lines().map(Movement::from_input).collect()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file(2022, "examples", 5).unwrap();
         assert_eq!(part_one(&input), Some("CMZ".to_string()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file(2022, "examples", 5).unwrap();
         assert_eq!(part_two(&input), Some("MCD".to_string()));
     }
 }
@@ -0,0 +1,35 @@
+advent_of_code::solution!(2022, 6);
+advent_of_code::example_tests!();
+
+use std::collections::HashSet;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    find_pattern(input, 4)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    find_pattern(input, 14)
+}
+
+fn find_pattern(input: &str, size: usize) -> Option<u32> {
+    let mut code = vec![];
+    let mut final_index = 0;
+
+    for (index, char) in input.chars().enumerate() {
+        code.push(char);
+        if code.len() == size {
+            if is_composed_by_unique_elements(&code) {
+                final_index = index + 1;
+                break;
+            }
+            code = code[1..].to_vec();
+        }
+    }
+
+    Some(final_index as u32)
+}
+
+fn is_composed_by_unique_elements(code: &[char]) -> bool {
+    let unique_chars: HashSet<&char> = code.iter().collect();
+    unique_chars.len() == code.len()
+}
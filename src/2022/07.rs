@@ -1,3 +1,5 @@
+advent_of_code::solution!(2022, 7);
+
 use std::collections::HashMap;
 use std::ops::Add;
 
@@ -38,7 +40,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 // Tree definitions //
 //////////////////////
 #[derive(Debug, Clone)]
-struct TreeFile(String, u32);
+struct TreeFile(#[allow(dead_code)] String, u32);
 
 #[derive(Debug, Clone)]
 struct TreeFolder {
@@ -80,7 +82,7 @@ impl Path {
     }
 
     fn as_string(&self) -> String {
-        if self.0.len() == 0 {
+        if self.0.is_empty() {
             return String::from("/");
         }
         self.0
@@ -142,7 +144,6 @@ impl Fs {
                 }
             })
             .flatten()
-            .into_iter()
             .collect();
         func(files)
     }
@@ -155,7 +156,7 @@ impl Fs {
 enum TerminalOutput {
     Command(TerminalCommand),
     File(String, u32),
-    Dir(String),
+    Dir(#[allow(dead_code)] String),
 }
 
 impl TerminalOutput {
@@ -198,31 +199,26 @@ impl TerminalCommand {
 }
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 enum ChDirCommand {
     ChDir(String),
     ChDirBack,
     ChDirTop,
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file(2022, "examples", 7).unwrap();
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file(2022, "examples", 7).unwrap();
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
@@ -1,24 +1,26 @@
+advent_of_code::solution!(2022, 8, parse);
+
 use std::cmp;
 
-/// Executors
+// Executors
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn parse(input: &str) -> Wood {
+    Wood::from_input(input)
+}
+
+pub fn part_one(wood: &Wood) -> Option<u32> {
     Some(
-        Wood::from_input(input)
-            .into_iter()
+        wood.into_iter()
             .filter(|tree| tree.visible_from_outside())
             .count() as u32,
     )
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Wood::from_input(input)
-        .into_iter()
-        .map(|tree| tree.scenic_score())
-        .max()
+pub fn part_two(wood: &Wood) -> Option<u32> {
+    wood.into_iter().map(|tree| tree.scenic_score()).max()
 }
 
-/// Types / Solution
+// Types / Solution
 
 enum ColumnDirection {
     Up,
@@ -31,8 +33,7 @@ enum RowDirection {
 }
 
 #[derive(Debug)]
-struct Wood {
-    position: (u32, u32),
+pub struct Wood {
     row_size: usize,
     col_size: usize,
     rows: Vec<Row>,
@@ -42,7 +43,6 @@ impl Wood {
     fn from_input(input: &str) -> Self {
         let rows: Vec<Row> = input.lines().map(Row::from_input).collect();
         Self {
-            position: (0, 0),
             row_size: rows[0].trees.len(),
             col_size: rows.len(),
             rows,
@@ -84,18 +84,35 @@ impl Wood {
     }
 }
 
-impl Iterator for Wood {
+impl<'a> IntoIterator for &'a Wood {
+    type Item = Tree;
+    type IntoIter = Trees<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Trees {
+            wood: self,
+            position: (0, 0),
+        }
+    }
+}
+
+pub struct Trees<'a> {
+    wood: &'a Wood,
+    position: (u32, u32),
+}
+
+impl Iterator for Trees<'_> {
     type Item = Tree;
 
     fn next(&mut self) -> Option<Self::Item> {
         let (x, y) = self.position;
-        if x as usize == self.row_size - 1 {
+        if x as usize == self.wood.row_size - 1 {
             self.position = (0, y + 1);
         } else {
             self.position = (x + 1, y);
         }
-        if x as usize <= self.row_size - 1 && y as usize <= self.col_size - 1 {
-            Some(self.get_tree(x as usize + 1, y as usize + 1))
+        if (x as usize) < self.wood.row_size && (y as usize) < self.wood.col_size {
+            Some(self.wood.get_tree(x as usize + 1, y as usize + 1))
         } else {
             None
         }
@@ -120,7 +137,7 @@ impl Row {
 }
 
 #[derive(Debug)]
-struct Tree {
+pub struct Tree {
     size: u32,
     top: Vec<u32>,
     right: Vec<u32>,
@@ -128,7 +145,7 @@ struct Tree {
     left: Vec<u32>,
 }
 
-fn maximum(values: &Vec<u32>) -> u32 {
+fn maximum(values: &[u32]) -> u32 {
     values.iter().fold(0, |maximum, a| cmp::max(maximum, *a))
 }
 
@@ -155,7 +172,7 @@ impl Tree {
     }
 }
 
-fn scenic_score(reference: u32, tree_row: &Vec<u32>) -> u32 {
+fn scenic_score(reference: u32, tree_row: &[u32]) -> u32 {
     let mut final_score = 0;
     for tree in tree_row {
         final_score += 1;
@@ -166,15 +183,7 @@ fn scenic_score(reference: u32, tree_row: &Vec<u32>) -> u32 {
     final_score
 }
 
-/// Main
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-/// Tests
+// Tests
 
 #[cfg(test)]
 mod tests {
@@ -182,13 +191,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_one(&input), Some(21));
+        let input = advent_of_code::read_file(2022, "examples", 8).unwrap();
+        assert_eq!(part_one(&parse(&input)), Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_two(&input), Some(8));
+        let input = advent_of_code::read_file(2022, "examples", 8).unwrap();
+        assert_eq!(part_two(&parse(&input)), Some(8));
     }
 }
@@ -1,5 +1,6 @@
+advent_of_code::solution!(2022, 9);
+
 use std::collections::HashSet;
-use std::iter::repeat;
 
 pub fn part_one(input: &str) -> Option<u32> {
     let movements: Vec<Movement> = input.lines().map(Movement::from_input).collect();
@@ -61,7 +62,7 @@ impl Canvas {
     fn new(num_tails: u32) -> Self {
         let mut tail_positions = HashSet::new();
         tail_positions.insert(Position::start());
-        let tails = repeat(Position::start()).take(num_tails as usize).collect();
+        let tails = vec![Position::start(); num_tails as usize];
         Self {
             tail_positions,
             head: Position::start(),
@@ -186,25 +187,19 @@ impl Movement {
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = advent_of_code::read_file(2022, "examples", 9).unwrap();
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = advent_of_code::read_file(2022, "examples", 9).unwrap();
         assert_eq!(part_two(&input), Some(1));
     }
 }
@@ -1,3 +1,5 @@
+advent_of_code::solution!(2022, 10);
+
 use std::ops::RangeInclusive;
 
 pub fn part_one(input: &str) -> Option<i32> {
@@ -66,11 +68,11 @@ impl CommandList {
                 }
             }
 
-            if iteration as u32 >= num {
+            if iteration >= num {
                 break;
             }
         }
-        out as i32
+        out
     }
 
     fn len(&self) -> usize {
@@ -100,35 +102,27 @@ impl Command {
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file(2022, "examples", 10).unwrap();
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(
-            part_two(&input),
-            Some(String::from(
-                "##..##..##..##..##..##..##..##..##..##..
+        let input = advent_of_code::read_file(2022, "examples", 10).unwrap();
+        advent_of_code::assert_grid_eq!(
+            part_two(&input).unwrap(),
+            "##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
 #####.....#####.....#####.....#####.....
 ######......######......######......####
 #######.......#######.......#######....."
-            ))
         );
     }
 }
@@ -1,23 +1,30 @@
+advent_of_code::solution!(2022, 11);
+
 use itertools::Itertools;
 use regex::Regex;
 use std::cell::RefCell;
+use std::error::Error;
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &str) -> Result<u64, Box<dyn Error>> {
     const DIVIDE_WORRY: i64 = 3;
     const ROUNDS: i64 = 20;
-    let monkey_business_level = calc_monkey_business(input, DIVIDE_WORRY, ROUNDS);
-    Some(monkey_business_level as u64)
+    let monkey_business_level = calc_monkey_business(input, DIVIDE_WORRY, ROUNDS)?;
+    Ok(monkey_business_level as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &str) -> Result<u64, Box<dyn Error>> {
     const DIVIDE_WORRY: i64 = 1;
     const ROUNDS: i64 = 10000;
-    let monkey_business_level = calc_monkey_business(input, DIVIDE_WORRY, ROUNDS);
-    Some(monkey_business_level as u64)
+    let monkey_business_level = calc_monkey_business(input, DIVIDE_WORRY, ROUNDS)?;
+    Ok(monkey_business_level as u64)
 }
 
-fn calc_monkey_business(input: &str, divide_worry: i64, rounds: i64) -> i64 {
-    let monkeys = parse_monkeys(input).unwrap();
+fn calc_monkey_business(
+    input: &str,
+    divide_worry: i64,
+    rounds: i64,
+) -> Result<i64, Box<dyn Error>> {
+    let monkeys = parse_monkeys(input)?;
     let modulus = monkeys.iter().fold(1, |a, m| a * m.test_num);
     for _ in 0..rounds {
         for monkey in &monkeys {
@@ -33,30 +40,23 @@ fn calc_monkey_business(input: &str, divide_worry: i64, rounds: i64) -> i64 {
         .map(|m| m.inspected_count())
         .sorted_by(|a, b| b.cmp(a))
         .take(2)
-        .fold(1, |a, x| a * x);
+        .product();
 
-    monkey_business_level
+    Ok(monkey_business_level)
 }
 
 /// Returns the string contents of the first matched capture group.
 pub fn re_capture_group<'a>(re: &Regex, text: &'a str) -> Option<&'a str> {
-    re.captures(text)
-        .and_then(|c| c.get(1))
-        .and_then(|g| Some(g.as_str()))
+    re.captures(text).and_then(|c| c.get(1)).map(|g| g.as_str())
 }
 
 /// Returns the string contents of the matched capture groups.
 pub fn re_capture_groups<'a>(re: &Regex, text: &'a str) -> Option<Vec<&'a str>> {
-    re.captures(text).map(|c| {
-        c.iter()
-            .skip(1)
-            .filter_map(|x| x)
-            .map(|x| x.as_str())
-            .collect_vec()
-    })
+    re.captures(text)
+        .map(|c| c.iter().skip(1).flatten().map(|x| x.as_str()).collect_vec())
 }
 
-fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, Box<dyn std::error::Error>> {
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, Box<dyn Error>> {
     let err = "invalid input";
     let items_re = Regex::new(r"Starting items: (.*)\n")?;
     let operation_re = Regex::new(r"Operation: new = (\S+) (\S) (\S+)")?;
@@ -135,7 +135,7 @@ impl Monkey {
     }
 
     fn has_items(&self) -> bool {
-        self.items.borrow().len() > 0
+        !self.items.borrow().is_empty()
     }
 
     fn inspected_count(&self) -> i64 {
@@ -143,25 +143,19 @@ impl Monkey {
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_one(&input), Some(10605));
+        let input = advent_of_code::read_file(2022, "examples", 11).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 10605);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_two(&input), Some(2713310158));
+        let input = advent_of_code::read_file(2022, "examples", 11).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 2713310158);
     }
 }
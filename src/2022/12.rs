@@ -0,0 +1,251 @@
+advent_of_code::solution!(2022, 12);
+advent_of_code::differential_tests! {
+    generate: reference::generate,
+    part_one: reference::part_one,
+    part_two: reference::part_two,
+}
+
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+struct PathFinder {
+    map: Vec<Vec<u8>>,
+    distances: Vec<Vec<u16>>,
+    start: (usize, usize),
+    end: (usize, usize),
+    width: usize,
+    height: usize,
+}
+
+impl PathFinder {
+    fn dijkstra(&mut self) {
+        let mut search_queue = VecDeque::with_capacity(self.width * self.height);
+        search_queue.push_back(self.end);
+
+        while !search_queue.is_empty() {
+            let current = search_queue.pop_front().unwrap();
+            let new_dist = self.distances[current.1][current.0] + 1;
+
+            // Check which neighbours can reach the current position.
+            // If their distance needs updating, update their distance and add them for future checking
+            for (dx, dy) in &[(0, -1), (-1, 0), (1, 0), (0, 1)] {
+                let x = current.0 as isize + dx;
+                let y = current.1 as isize + dy;
+
+                if x >= 0 && x < self.width as isize && y >= 0 && y < self.height as isize {
+                    // Neighbour exists...
+                    if self.map[current.1][current.0] <= self.map[y as usize][x as usize] + 1 {
+                        // ...and can reach the current position...
+                        if self.distances[y as usize][x as usize] > new_dist {
+                            // ...and this is a shorter path
+                            self.distances[y as usize][x as usize] = new_dist;
+                            search_queue.push_back((x as usize, y as usize));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn from_input(input: &str) -> Self {
+        let mut start = (0, 0);
+        let mut end = (0, 0);
+        let map = input
+            .lines()
+            .enumerate()
+            .map(|(y, l)| {
+                l.char_indices()
+                    .map(|(x, c)| match c {
+                        'S' => {
+                            start = (x, y);
+                            b'a'
+                        }
+                        'E' => {
+                            end = (x, y);
+                            b'z'
+                        }
+                        n if n.is_lowercase() => n as u8,
+                        _ => panic!("invalid char {c}"),
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+        let width = map[0].len();
+        let height = map.len();
+        let mut distances = map
+            .iter()
+            .map(|r| r.iter().map(|_| u16::MAX).collect_vec())
+            .collect_vec();
+        distances[end.1][end.0] = 0;
+        PathFinder {
+            map,
+            distances,
+            start,
+            end,
+            width,
+            height,
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut path_finder = PathFinder::from_input(input);
+    path_finder.dijkstra();
+    Some(path_finder.distances[path_finder.start.1][path_finder.start.0] as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut path_finder = PathFinder::from_input(input);
+    path_finder.dijkstra();
+    let steps = path_finder
+        .map
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter().enumerate().filter_map(
+                move |(x, h)| {
+                    if *h == b'a' {
+                        Some((x, y))
+                    } else {
+                        None
+                    }
+                },
+            )
+        })
+        .map(|(x, y)| path_finder.distances[y][x])
+        .min()
+        .unwrap();
+
+    Some(steps as u32)
+}
+
+/// A breadth-first search from every start, to check the reverse search of `PathFinder`.
+/// Puzzle inputs always have a path from `S` to `E`, so the reference panics on maps without
+/// one, which skips them as invalid.
+#[cfg(test)]
+mod reference {
+    use advent_of_code::template::differential::Rng;
+    use std::collections::VecDeque;
+
+    type Position = (usize, usize);
+
+    fn parse(input: &str) -> (Vec<Vec<u8>>, Position, Position) {
+        let mut map: Vec<Vec<u8>> = input.lines().map(|l| l.bytes().collect()).collect();
+        let find = |map: &[Vec<u8>], c: u8| {
+            map.iter()
+                .enumerate()
+                .find_map(|(y, row)| row.iter().position(|h| *h == c).map(|x| (x, y)))
+                .expect("missing start or end")
+        };
+        let (start, end) = (find(&map, b'S'), find(&map, b'E'));
+        map[start.1][start.0] = b'a';
+        map[end.1][end.0] = b'z';
+        (map, start, end)
+    }
+
+    fn steps(map: &[Vec<u8>], start: Position, end: Position) -> Option<u32> {
+        let mut steps = vec![vec![None; map[0].len()]; map.len()];
+        steps[start.1][start.0] = Some(0);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some((x, y)) = queue.pop_front() {
+            let current = steps[y][x].unwrap();
+            if (x, y) == end {
+                return Some(current);
+            }
+            let neighbours = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (nx, ny) in neighbours {
+                if ny < map.len()
+                    && nx < map[ny].len()
+                    && map[ny][nx] <= map[y][x] + 1
+                    && steps[ny][nx].is_none()
+                {
+                    steps[ny][nx] = Some(current + 1);
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn part_one(input: &str) -> Option<u32> {
+        let (map, start, end) = parse(input);
+        Some(steps(&map, start, end).expect("no path from the start to the end"))
+    }
+
+    pub fn part_two(input: &str) -> Option<u32> {
+        part_one(input);
+        let (map, _, end) = parse(input);
+        (0..map.len())
+            .flat_map(|y| (0..map[y].len()).map(move |x| (x, y)))
+            .filter(|(x, y)| map[*y][*x] == b'a')
+            .filter_map(|start| steps(&map, start, end))
+            .min()
+    }
+
+    /// A map that rises from the start in one corner towards the end, with random bumps,
+    /// cliffs and valleys in the way.
+    pub fn generate(rng: &mut Rng) -> String {
+        // large enough to climb from `a` to `z` one level at a time.
+        let (width, height) = (rng.range(16, 25), rng.range(16, 25));
+        let start = (rng.range(0, 3), rng.range(0, 3));
+        let distance = |(x, y): Position| x.abs_diff(start.0) + y.abs_diff(start.1);
+        let end = loop {
+            let end = (rng.range(0, width), rng.range(0, height));
+            if (25..28).contains(&distance(end)) {
+                break end;
+            }
+        };
+
+        let mut input = String::new();
+        for y in 0..height {
+            for x in 0..width {
+                let c = match (x, y) {
+                    position if position == start => 'S',
+                    position if position == end => 'E',
+                    _ if rng.chance(1, 20) => 'a',
+                    position => {
+                        let bump = match rng.range(0, 10) {
+                            0 => 2,
+                            1 => 1,
+                            2 => usize::MAX,
+                            _ => 0,
+                        };
+                        let level = match bump {
+                            usize::MAX => distance(position).saturating_sub(2),
+                            bump => distance(position) + bump,
+                        };
+                        (b'a' + level.min(25) as u8) as char
+                    }
+                };
+                input.push(c);
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 12).unwrap();
+        assert_eq!(part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 12).unwrap();
+        assert_eq!(part_two(&input), Some(29));
+    }
+}
@@ -2,6 +2,7 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::template::config::{config, load_config};
 use std::io::Write;
 use std::path::PathBuf;
 use std::{env::temp_dir, io, process::Command};
@@ -9,14 +10,16 @@ use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
     })
 }
 
@@ -33,6 +36,11 @@ fn exit_with_status(status: i32, path: &PathBuf) -> ! {
 }
 
 fn main() {
+    if let Err(e) = load_config() {
+        eprintln!("Failed to load the settings: {}", e);
+        process::exit(1);
+    }
+
     // acquire a temp file path to write aoc-cli output to.
     // aoc-cli expects this file not to be present - delete just in case.
     let mut tmp_file_path = temp_dir();
@@ -47,8 +55,7 @@ fn main() {
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let input_path = advent_of_code::file_path(args.year, "inputs", args.day);
 
     // check if aoc binary exists and is callable.
     if Command::new("aoc").arg("-V").output().is_err() {
@@ -56,20 +63,19 @@ fn main() {
         exit_with_status(1, &tmp_file_path);
     }
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec![
+    let mut cmd_args = vec![
+        "--year".into(),
+        args.year.to_string(),
         "--input-file".into(),
         tmp_file_path.to_string_lossy().to_string(),
         "--day".into(),
         args.day.to_string(),
-        "download".into(),
-    ]);
+    ];
+    if let Some(session_file) = &config().session_file {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(session_file.to_string_lossy().to_string());
+    }
+    cmd_args.push("download".into());
 
     println!("Downloading input with >aoc {}", cmd_args.join(" "));
 
@@ -91,10 +97,18 @@ fn main() {
         }
     }
 
+    if let Err(e) = fs::create_dir_all(advent_of_code::data_dir(args.year, "inputs")) {
+        eprintln!("could not create the input directory: {}", e);
+        exit_with_status(1, &tmp_file_path);
+    }
+
     match fs::copy(&tmp_file_path, &input_path) {
         Ok(_) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
             exit_with_status(0, &tmp_file_path);
         }
         Err(e) => {
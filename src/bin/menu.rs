@@ -0,0 +1,387 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::template::answers::Answers;
+use advent_of_code::template::config::load_config;
+use advent_of_code::template::history::History;
+use advent_of_code::{ANSI_BOLD, ANSI_CLEAR, ANSI_ITALIC, ANSI_RESET};
+use std::ffi::OsString;
+use std::io::{self, Read, Write};
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
+use std::{env, fs};
+
+const ANSI_HIDE_CURSOR: &str = "\x1b[?25l";
+const ANSI_SHOW_CURSOR: &str = "\x1b[?25h";
+
+struct Args {
+    year: u16,
+    /// Build the solutions in release mode.
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        release: args.contains("--release"),
+    })
+}
+
+/// What is known about a part without running it.
+struct PartStatus {
+    /// Its answer is recorded in `answers.toml`, which doesn't mean that the last run found it.
+    recorded: bool,
+    /// Its most recent timing in `history.csv`, for the profile of the menu.
+    last: Option<Duration>,
+}
+
+struct DayStatus {
+    day: u8,
+    has_input: bool,
+    parts: [PartStatus; 2],
+}
+
+/// Every `src/<year>/NN.rs` module, in day order.
+fn scaffolded_days(year: u16) -> Vec<u8> {
+    let mut days: Vec<u8> = fs::read_dir(advent_of_code::year_dir(year))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let day = name.strip_suffix(".rs")?;
+            match day.len() == 2 {
+                true => day.parse().ok(),
+                false => None,
+            }
+        })
+        .collect();
+    days.sort();
+    days
+}
+
+fn load_status(year: u16, profile: &str) -> Result<Vec<DayStatus>, String> {
+    let answers = Answers::load(year)?;
+    let history = History::load(year)?;
+
+    Ok(scaffolded_days(year)
+        .into_iter()
+        .map(|day| DayStatus {
+            day,
+            has_input: fs::metadata(advent_of_code::file_path(year, "inputs", day))
+                .is_ok_and(|metadata| metadata.len() > 0),
+            parts: [1, 2].map(|part| PartStatus {
+                recorded: answers.get(day, part).is_some(),
+                last: history.last(profile, day, part),
+            }),
+        })
+        .collect())
+}
+
+/// Switches the terminal to reading single key presses without echo, using `stty`.
+/// Restores the previous settings when dropped. Without `stty`, keys are read after Enter.
+struct RawMode {
+    saved: Option<String>,
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    match output.status.success() {
+        true => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+        false => None,
+    }
+}
+
+impl RawMode {
+    fn enable() -> Self {
+        let saved = stty(&["-g"]);
+        if saved.is_some() {
+            stty(&["-icanon", "-echo", "min", "1"]);
+        }
+        print!("{}", ANSI_HIDE_CURSOR);
+        Self { saved }
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            stty(&[saved]);
+        }
+        print!("{}", ANSI_SHOW_CURSOR);
+        io::stdout().flush().ok();
+    }
+}
+
+enum Key {
+    Up,
+    Down,
+    Escape,
+    Char(char),
+}
+
+/// Reads a key press. Arrow keys arrive as the escape sequences `ESC [ A` and `ESC [ B`,
+/// an `ESC` that isn't followed by anything within a tenth of a second is the Escape key.
+fn read_key() -> Option<Key> {
+    let mut stdin = io::stdin().lock();
+    let mut byte = [0];
+    stdin.read_exact(&mut byte).ok()?;
+    if byte[0] != 0x1b {
+        return Some(Key::Char(char::from(byte[0])));
+    }
+
+    // reads return nothing after the timeout, `RawMode` restores the blocking reads.
+    stty(&["min", "0", "time", "1"]);
+    let mut sequence = Vec::new();
+    let mut buffer = [0; 2];
+    while sequence.len() < 2 {
+        match stdin.read(&mut buffer[..2 - sequence.len()]) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => sequence.extend_from_slice(&buffer[..read]),
+        }
+    }
+    match sequence.as_slice() {
+        [] => Some(Key::Escape),
+        [b'[', b'A'] => Some(Key::Up),
+        [b'[', b'B'] => Some(Key::Down),
+        _ => Some(Key::Char('\x1b')),
+    }
+}
+
+/// A status that takes up two columns, like the emoji.
+fn part_label(part: &PartStatus) -> &'static str {
+    match (part.recorded, part.last) {
+        (true, _) => "📝",
+        (false, Some(_)) => "❔",
+        (false, None) => "- ",
+    }
+}
+
+fn time_label(part: &PartStatus) -> String {
+    match part.last {
+        Some(last) => format!("{:.2?}", last),
+        None => "-".to_string(),
+    }
+}
+
+fn render(args: &Args, days: &[DayStatus], selected: usize, example: bool) {
+    let mut out = String::from(ANSI_CLEAR);
+    out += &format!(
+        "{}🎄 Advent of Code {} 🎄{}\n\n",
+        ANSI_BOLD, args.year, ANSI_RESET
+    );
+    out += &format!(
+        "    Day   Part 1   Part 2   {:>16}   {:>16}\n",
+        "Last part 1", "Last part 2"
+    );
+
+    for (index, day) in days.iter().enumerate() {
+        let (cursor, style) = match index == selected {
+            true => ("▶", ANSI_BOLD),
+            false => (" ", ""),
+        };
+        out += &format!(
+            "{} {}   {:02}     {}       {}     {:>16}   {:>16}{}",
+            cursor,
+            style,
+            day.day,
+            part_label(&day.parts[0]),
+            part_label(&day.parts[1]),
+            time_label(&day.parts[0]),
+            time_label(&day.parts[1]),
+            ANSI_RESET
+        );
+        if !day.has_input {
+            out += &format!("   {}(no input){}", ANSI_ITALIC, ANSI_RESET);
+        }
+        out += "\n";
+    }
+
+    out += &format!(
+        "\n📝 answer recorded, ❔ solved, - not solved. Timings of the last {} run of `cargo all`.\n",
+        profile(args)
+    );
+    out += &format!(
+        "Runs on the {}{}{}.\n\n",
+        ANSI_BOLD,
+        match example {
+            true => "example input",
+            false => "puzzle input",
+        },
+        ANSI_RESET
+    );
+    out += &format!(
+        "{}↑/↓ select · 1/2 run a part · e switch input · t run tests · p read puzzle · q/Esc quit{}\n",
+        ANSI_ITALIC, ANSI_RESET
+    );
+
+    print!("{}", out);
+    io::stdout().flush().ok();
+}
+
+fn profile(args: &Args) -> &'static str {
+    match args.release {
+        true => "release",
+        false => "debug",
+    }
+}
+
+/// Runs a cargo subcommand, with `--release` if the menu was started with it.
+fn cargo(args: &Args, command: &[&str]) {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut cmd = Command::new(cargo);
+    cmd.arg(command[0]).arg("--quiet");
+    if args.release {
+        cmd.arg("--release");
+    }
+    if let Err(e) = cmd.args(&command[1..]).status() {
+        eprintln!("Failed to spawn cargo: {}", e);
+    }
+}
+
+/// Runs a part of a day through `cargo all`, which checks the answer and records the timing.
+fn run_part(args: &Args, day: u8, part: u8, example: bool) {
+    let (year, day, part) = (args.year.to_string(), day.to_string(), part.to_string());
+    let mut command = vec![
+        "run",
+        "--bin",
+        "advent_of_code",
+        "--",
+        "--year",
+        &year,
+        "--part",
+        &part,
+    ];
+    if example {
+        command.push("--example");
+    }
+    command.push(&day);
+    cargo(args, &command);
+}
+
+/// Prints the puzzle with aoc-cli, or opens it in the browser if aoc-cli isn't installed.
+fn read_puzzle(year: u16, day: u8) {
+    if Command::new("aoc").arg("-V").output().is_ok() {
+        let status = Command::new("aoc")
+            .args([
+                "--year",
+                &year.to_string(),
+                "--day",
+                &day.to_string(),
+                "read",
+            ])
+            .status();
+        if status.is_ok_and(|status| status.success()) {
+            return;
+        }
+    }
+
+    let url = format!("https://adventofcode.com/{}/day/{}", year, day);
+    let opened = ["xdg-open", "open"].iter().any(|opener| {
+        Command::new(opener)
+            .arg(&url)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+    });
+    if !opened {
+        println!("Read the puzzle at {}", url);
+    }
+}
+
+/// Leaves the menu for an action and waits for Enter before returning to it.
+fn run_action(title: &str, action: impl FnOnce()) {
+    print!("{}", ANSI_CLEAR);
+    println!("{}🎄 {} 🎄{}", ANSI_BOLD, title, ANSI_RESET);
+    action();
+    println!();
+    print!(
+        "{}Press Enter to return to the menu.{}",
+        ANSI_ITALIC, ANSI_RESET
+    );
+    io::stdout().flush().ok();
+    io::stdin().read_line(&mut String::new()).ok();
+}
+
+fn main() {
+    if let Err(e) = load_config() {
+        eprintln!("Failed to load the settings: {}", e);
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let load = || match load_status(args.year, profile(&args)) {
+        Ok(days) if days.is_empty() => {
+            eprintln!(
+                "There are no days for {}. Run `cargo scaffold <day>` first.",
+                args.year
+            );
+            process::exit(1);
+        }
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Failed to load the state of the days: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut days = load();
+    let mut selected = days.len() - 1;
+    let mut example = false;
+
+    loop {
+        let raw_mode = RawMode::enable();
+        render(&args, &days, selected, example);
+        let key = read_key();
+        drop(raw_mode);
+
+        let day = days[selected].day;
+        let input = match example {
+            true => "example",
+            false => "puzzle input",
+        };
+        match key {
+            None | Some(Key::Escape | Key::Char('q')) => break,
+            Some(Key::Up | Key::Char('k')) => selected = selected.saturating_sub(1),
+            Some(Key::Down | Key::Char('j')) => selected = (selected + 1).min(days.len() - 1),
+            Some(Key::Char('e')) => example = !example,
+            Some(Key::Char(part @ ('1' | '2'))) => {
+                let part = part as u8 - b'0';
+                let title = format!("Day {:02}, part {} on the {}", day, part, input);
+                run_action(&title, || run_part(&args, day, part, example));
+            }
+            Some(Key::Char('t')) => {
+                let binary = advent_of_code::binary_name(args.year, day);
+                run_action(&format!("Tests of day {:02}", day), || {
+                    cargo(&args, &["test", "--bin", &binary])
+                });
+            }
+            Some(Key::Char('p')) => {
+                run_action(&format!("Puzzle of day {:02}", day), || {
+                    read_puzzle(args.year, day)
+                });
+            }
+            Some(_) => continue,
+        }
+        // actions record answers and timings.
+        days = load();
+    }
+
+    print!("{}", ANSI_CLEAR);
+}
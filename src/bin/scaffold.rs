@@ -2,71 +2,198 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::template::config::load_config;
+use advent_of_code::template::examples::examples_path;
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const MODULE_TEMPLATE: &str = r###"use std::error::Error;
+
+advent_of_code::solution!(YEAR, DAY);
+advent_of_code::example_tests!();
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Box<dyn Error>> {
+    Ok(None)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+pub fn part_two(input: &str) -> Result<Option<u32>, Box<dyn Error>> {
+    Ok(None)
 }
+"###;
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+const EXAMPLES_TEMPLATE: &str = r###"# Examples of day DAY, checked by `cargo test --bin BINARY`.
+# Add a table per example, with the input in a file or inline: input = '''...'''
+# The test of a part fails until at least one example has an expected answer for it.
+
+[example]
+file = "FILE"
+# part_one = 0
+# part_two = 0
+"###;
+
+const REGISTRY_HEADER: &str = r###"/*
+ * This file is generated by `cargo scaffold`. Do not edit it by hand.
+ * It registers every solution in `./<year>/` with the all-days runner.
+ */
+use advent_of_code::Solution;
+"###;
+
+const BINARIES_START: &str =
+    "# Solution binaries, generated by `cargo scaffold`. Do not edit them by hand.\n";
+const BINARIES_END: &str = "# End of solution binaries.\n";
+
+struct Args {
+    day: u8,
+    year: u16,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_str()?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+    })
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
+
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Lists every `src/<year>/NN.rs` module, sorted by year and day.
+fn find_solutions() -> Result<Vec<(u16, String)>, std::io::Error> {
+    let is_number =
+        |name: &str, len: usize| name.len() == len && name.chars().all(|c| c.is_ascii_digit());
+    let mut solutions = Vec::new();
+
+    for entry in fs::read_dir(advent_of_code::crate_dir().join("src"))? {
+        let entry = entry?;
+        let year = match entry.file_name().into_string() {
+            Ok(name) if is_number(&name, 4) && entry.path().is_dir() => name,
+            _ => continue,
+        };
+        for day in fs::read_dir(entry.path())? {
+            let name = match day?.file_name().into_string() {
+                Ok(name) => name,
+                Err(_) => continue,
+            };
+            if let Some(day) = name.strip_suffix(".rs").filter(|day| is_number(day, 2)) {
+                solutions.push((year.parse().unwrap(), day.to_string()));
+            }
+        }
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    solutions.sort();
+    Ok(solutions)
+}
+
+/// Rewrites `src/days.rs` so that it includes every `src/<year>/NN.rs` module.
+fn write_registry(solutions: &[(u16, String)]) -> Result<(), std::io::Error> {
+    let mut contents = String::from(REGISTRY_HEADER);
+    for (year, day) in solutions {
+        contents += &format!(
+            "\n#[path = \"{}/{}.rs\"]\nmod y{}_day{};\n",
+            year, day, year, day
+        );
+    }
+    contents += "\npub fn all() -> Vec<&'static dyn Solution> {\n    vec![\n";
+    for (year, day) in solutions {
+        contents += &format!("        &y{}_day{}::Day,\n", year, day);
     }
+    contents += "    ]\n}\n";
+
+    fs::write(advent_of_code::crate_dir().join("src/days.rs"), contents)
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Rewrites the `[[bin]]` targets of every solution at the end of `Cargo.toml`.
+fn write_binaries(solutions: &[(u16, String)]) -> Result<(), std::io::Error> {
+    let manifest_path = advent_of_code::crate_dir().join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let manifest = match manifest.find(BINARIES_START) {
+        Some(start) => {
+            let end = manifest[start..]
+                .find(BINARIES_END)
+                .map(|end| start + end + BINARIES_END.len())
+                .unwrap_or(manifest.len());
+            format!("{}{}", &manifest[..start], &manifest[end..])
+        }
+        None => format!("{}\n", manifest),
+    };
+
+    let mut binaries = String::from(BINARIES_START);
+    for (year, day) in solutions {
+        binaries += &format!(
+            "[[bin]]\nname = \"{}\"\npath = \"src/{}/{}.rs\"\n\n",
+            advent_of_code::binary_name(*year, day.parse().unwrap()),
+            year,
+            day
+        );
+    }
+    binaries += BINARIES_END;
+
+    fs::write(manifest_path, format!("{}{}", manifest, binaries))
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Registers every solution module with the all-days runner and as a binary.
+fn register() -> Result<(), std::io::Error> {
+    let solutions = find_solutions()?;
+    write_registry(&solutions)?;
+    write_binaries(&solutions)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// A path relative to the crate root, for messages.
+fn relative(path: &Path) -> String {
+    path.strip_prefix(advent_of_code::crate_dir())
+        .unwrap_or(path)
+        .display()
+        .to_string()
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    if let Err(e) = load_config() {
+        eprintln!("Failed to load the settings: {}", e);
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let day_padded = format!("{:02}", args.day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    // inputs and examples live wherever `aoc.toml` puts them.
+    let input_path = advent_of_code::file_path(args.year, "inputs", args.day);
+    let example_path = advent_of_code::file_path(args.year, "examples", args.day);
+    let examples_path = examples_path(args.year, args.day);
+    let module_path = advent_of_code::year_dir(args.year).join(format!("{}.rs", day_padded));
+
+    for dir in [
+        advent_of_code::year_dir(args.year),
+        advent_of_code::data_dir(args.year, "inputs"),
+        advent_of_code::data_dir(args.year, "examples"),
+    ] {
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("Failed to create directory for {}: {}", args.year, e);
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +203,13 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    let module = MODULE_TEMPLATE
+        .replace("YEAR", &args.year.to_string())
+        .replace("DAY", &args.day.to_string());
+
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", relative(&module_path));
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -86,9 +217,24 @@ fn main() {
         }
     }
 
+    match register() {
+        Ok(_) => {
+            println!("Registered module in \"src/days.rs\" and \"Cargo.toml\"");
+        }
+        Err(e) => {
+            eprintln!("Failed to update the day registry: {}", e);
+            // without the module, the day can be scaffolded again.
+            if fs::remove_file(&module_path).is_ok() {
+                eprintln!("Removed module file \"{}\"", relative(&module_path));
+                register().ok();
+            }
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", relative(&input_path));
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -98,7 +244,7 @@ fn main() {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", relative(&example_path));
         }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
@@ -106,9 +252,26 @@ fn main() {
         }
     }
 
+    let examples = EXAMPLES_TEMPLATE
+        .replace("DAY", &args.day.to_string())
+        .replace("BINARY", &advent_of_code::binary_name(args.year, args.day))
+        .replace("FILE", &format!("{}.txt", day_padded));
+
+    match safe_create_file(&examples_path).and_then(|mut file| file.write_all(examples.as_bytes()))
+    {
+        Ok(_) => {
+            println!("Created examples file \"{}\"", relative(&examples_path));
+        }
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+        Err(e) => {
+            eprintln!("Failed to create examples file: {}", e);
+            process::exit(1);
+        }
+    }
+
     println!("---");
     println!(
         "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        advent_of_code::binary_name(args.year, args.day)
     );
 }
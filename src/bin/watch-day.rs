@@ -0,0 +1,123 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::template::config::load_config;
+use advent_of_code::{ANSI_BOLD, ANSI_CLEAR, ANSI_ITALIC, ANSI_RESET};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::{self, Command};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, thread};
+
+struct Args {
+    day: u8,
+    year: u16,
+    interval: Duration,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_str()?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        interval: args
+            .opt_value_from_fn("--interval", advent_of_code::template::args::parse_seconds)?
+            .unwrap_or(Duration::from_millis(500)),
+    })
+}
+
+/// Modification time and size of a file, or `None` if it doesn't exist.
+type Fingerprint = Option<(SystemTime, u64)>;
+
+fn fingerprint(path: &PathBuf) -> Fingerprint {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Runs a cargo subcommand for the watched binary and returns whether it succeeded.
+fn cargo(args: &[&str]) -> bool {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    match Command::new(cargo).args(args).status() {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("Failed to spawn cargo: {}", e);
+            false
+        }
+    }
+}
+
+fn heading(title: &str) {
+    println!("{}🎄 {} 🎄{}", ANSI_BOLD, title, ANSI_RESET);
+}
+
+/// Rebuilds the solution, runs its tests on the example and then runs it on the puzzle input.
+fn run(binary: &str) {
+    heading("Build");
+    if !cargo(&["build", "--quiet", "--bin", binary]) {
+        return;
+    }
+
+    heading("Example tests");
+    cargo(&["test", "--quiet", "--bin", binary]);
+
+    heading("Puzzle input");
+    cargo(&["run", "--quiet", "--bin", binary]);
+}
+
+fn main() {
+    if let Err(e) = load_config() {
+        eprintln!("Failed to load the settings: {}", e);
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    let binary = advent_of_code::binary_name(args.year, args.day);
+    let paths = [
+        advent_of_code::year_dir(args.year).join(format!("{:02}.rs", args.day)),
+        advent_of_code::file_path(args.year, "examples", args.day),
+        advent_of_code::template::examples::examples_path(args.year, args.day),
+        advent_of_code::file_path(args.year, "inputs", args.day),
+    ];
+
+    if !paths[0].exists() {
+        eprintln!(
+            "Could not find \"{}\". Run `cargo scaffold {}` first.",
+            paths[0].display(),
+            args.day
+        );
+        process::exit(1);
+    }
+
+    let mut last = None;
+    loop {
+        let current: Vec<Fingerprint> = paths.iter().map(fingerprint).collect();
+        if last.as_ref() != Some(&current) {
+            print!("{}", ANSI_CLEAR);
+            run(&binary);
+            println!();
+            println!(
+                "{}👀 Watching {} for changes, press Ctrl+C to stop.{}",
+                ANSI_ITALIC,
+                paths
+                    .iter()
+                    .map(|path| format!("\"{}\"", path.display()))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                ANSI_RESET
+            );
+            // files saved during the run trigger another one.
+            last = Some(current);
+        }
+        thread::sleep(args.interval);
+    }
+}
@@ -0,0 +1,58 @@
+/*
+ * This file is generated by `cargo scaffold`. Do not edit it by hand.
+ * It registers every solution in `./<year>/` with the all-days runner.
+ */
+use advent_of_code::Solution;
+
+#[path = "2022/01.rs"]
+mod y2022_day01;
+
+#[path = "2022/02.rs"]
+mod y2022_day02;
+
+#[path = "2022/03.rs"]
+mod y2022_day03;
+
+#[path = "2022/04.rs"]
+mod y2022_day04;
+
+#[path = "2022/05.rs"]
+mod y2022_day05;
+
+#[path = "2022/06.rs"]
+mod y2022_day06;
+
+#[path = "2022/07.rs"]
+mod y2022_day07;
+
+#[path = "2022/08.rs"]
+mod y2022_day08;
+
+#[path = "2022/09.rs"]
+mod y2022_day09;
+
+#[path = "2022/10.rs"]
+mod y2022_day10;
+
+#[path = "2022/11.rs"]
+mod y2022_day11;
+
+#[path = "2022/12.rs"]
+mod y2022_day12;
+
+pub fn all() -> Vec<&'static dyn Solution> {
+    vec![
+        &y2022_day01::Day,
+        &y2022_day02::Day,
+        &y2022_day03::Day,
+        &y2022_day04::Day,
+        &y2022_day05::Day,
+        &y2022_day06::Day,
+        &y2022_day07::Day,
+        &y2022_day08::Day,
+        &y2022_day09::Day,
+        &y2022_day10::Day,
+        &y2022_day11::Day,
+        &y2022_day12::Day,
+    ]
+}
@@ -4,91 +4,330 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
 use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
 
 pub mod helpers;
+pub mod template;
+
+pub use template::{Answer, PartOutput, Solution, Stages};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: template::alloc::CountingAllocator = template::alloc::CountingAllocator;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Registers the day of a year with the all-days runner and generates its `main` function.
+/// Expects `part_one` and `part_two` to be defined in the calling module,
+/// returning any of the types listed in [`PartOutput`].
+///
+/// Pass the name of a parse function as third argument for a two-stage solution, e.g.
+/// `solution!(2022, 8, parse)`. The input is then parsed once by `parse(input: &str) -> T`,
+/// and `part_one` and `part_two` take `&T`. All three stages are timed separately.
+#[macro_export]
+macro_rules! solution {
+    (@day $year:expr, $day:expr) => {
+        const YEAR: u16 = $year;
+        const DAY: u8 = $day;
+
+        pub struct Day;
+
+        #[allow(dead_code)]
+        fn main() {
+            if let Err(e) = $crate::template::config::load_config() {
+                eprintln!("Failed to load the settings: {}", e);
+                std::process::exit(1);
+            }
+            let options = $crate::template::args::solve_options();
+            if options.all_inputs {
+                $crate::template::batch::solve_inputs(&Day, options);
+                return;
+            }
+            let input = &match $crate::read_input(YEAR, DAY, &options.input) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            };
+            $crate::template::runner::solve_day(&Day, input, options);
+        }
+    };
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@day $year, $day);
+
+        impl $crate::Solution for Day {
+            fn year(&self) -> u16 {
+                YEAR
+            }
+
+            fn day(&self) -> u8 {
+                DAY
+            }
+
+            fn part_one(&self, input: &str) -> Result<Option<$crate::Answer>, String> {
+                $crate::PartOutput::into_answer(part_one(input))
+            }
+
+            fn part_two(&self, input: &str) -> Result<Option<$crate::Answer>, String> {
+                $crate::PartOutput::into_answer(part_two(input))
+            }
+        }
+    };
+    ($year:expr, $day:expr, $parse:ident) => {
+        $crate::solution!(@day $year, $day);
+
+        impl $crate::Solution for Day {
+            fn year(&self) -> u16 {
+                YEAR
+            }
 
+            fn day(&self) -> u8 {
+                DAY
+            }
+
+            fn part_one(&self, input: &str) -> Result<Option<$crate::Answer>, String> {
+                $crate::PartOutput::into_answer(part_one(&$parse(input)))
+            }
+
+            fn part_two(&self, input: &str) -> Result<Option<$crate::Answer>, String> {
+                $crate::PartOutput::into_answer(part_two(&$parse(input)))
+            }
+
+            fn run(&self, input: &str, stages: &mut dyn $crate::Stages) {
+                let mut parsed = None;
+                stages.parse(&mut || parsed = Some($parse(input)));
+                // `stages` doesn't run the parts if parsing panicked.
+                let parsed = || parsed.as_ref().expect("the input was not parsed");
+                stages.part(1, &mut || $crate::PartOutput::into_answer(part_one(parsed())));
+                stages.part(2, &mut || $crate::PartOutput::into_answer(part_two(parsed())));
+            }
+        }
+    };
+}
+
+/// Generates a test per part that checks the solution against the examples in
+/// `src/<year>/examples/NN.toml`. Expects to be called after `solution!`.
 #[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod example_tests {
+            #[test]
+            fn part_one() {
+                $crate::template::examples::check(&super::Day, 1);
+            }
+
+            #[test]
+            fn part_two() {
+                $crate::template::examples::check(&super::Day, 2);
+            }
+        }
+    };
+}
+
+/// Generates a test per part that compares the solution with a slow, obviously correct
+/// reference implementation on generated inputs, see [`template::differential`].
+/// `generate` takes a [`template::differential::Rng`] and returns an input, the references
+/// take an input like `part_one` and `part_two`. Expects to be called after `solution!`.
+///
+/// ```ignore
+/// advent_of_code::differential_tests! {
+///     generate: reference::generate,
+///     part_one: reference::part_one,
+/// }
+/// ```
+#[macro_export]
+macro_rules! differential_tests {
+    (generate: $generate:path, $(part_one: $one:path,)? $(part_two: $two:path,)?) => {
+        #[cfg(test)]
+        mod differential_tests {
+            use super::*;
+
+            $(
+                #[test]
+                fn part_one() {
+                    $crate::template::differential::check(
+                        1,
+                        $generate,
+                        |input| $crate::Solution::part_one(&Day, input),
+                        |input| $crate::PartOutput::into_answer($one(input)),
                     );
                 }
-                None => {
-                    println!("not solved.")
+            )?
+
+            $(
+                #[test]
+                fn part_two() {
+                    $crate::template::differential::check(
+                        2,
+                        $generate,
+                        |input| $crate::Solution::part_two(&Day, input),
+                        |input| $crate::PartOutput::into_answer($two(input)),
+                    );
                 }
-            }
+            )?
         }
+    };
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// Asserts that two grids or other multi-line answers are equal, e.g. the picture of a screen.
+/// On failure, prints both side by side with the differing cells marked and listed by row and
+/// column, see [`template::diff::grid_diff`]. Takes anything that dereferences to `str`.
+///
+/// ```ignore
+/// advent_of_code::assert_grid_eq!(part_two(&input).unwrap(), "##..\n#.#.");
+/// ```
+#[macro_export]
+macro_rules! assert_grid_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        if let Some(diff) = $crate::template::diff::grid_diff(&$actual, &$expected) {
+            panic!("assertion failed: grids are not equal\n{}", diff);
+        }
+    };
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Environment variable that points to a directory with puzzle inputs, replacing `src/<year>/inputs/`.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Environment variable that overrides the default year of `aoc.toml`, e.g. `AOC_YEAR=2021 cargo all`.
+pub const YEAR_ENV: &str = "AOC_YEAR";
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// The year of the template, used if neither `AOC_YEAR` nor `aoc.toml` set one. Its binaries
+/// have short names, see [`binary_name`].
+pub const TEMPLATE_YEAR: u16 = 2022;
+
+/// The year that `cargo scaffold`, `cargo download` and `cargo all` work on unless `--year` is passed.
+/// Read from `AOC_YEAR` if it is set to a year, otherwise from `year` in `aoc.toml`.
+pub fn default_year() -> u16 {
+    env::var(YEAR_ENV)
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .or(template::config::config().year)
+        .unwrap_or(TEMPLATE_YEAR)
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// The root directory of this crate, independent of the current working directory.
+pub fn crate_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+/// The `src` directory of this crate, independent of the current working directory.
+pub(crate) fn src_dir() -> PathBuf {
+    crate_dir().join("src")
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// The directory with the solutions, inputs and examples of a year, e.g. `src/2022`.
+pub fn year_dir(year: u16) -> PathBuf {
+    src_dir().join(year.to_string())
+}
+
+/// Binaries of the template year are named after the day, other years are prefixed, e.g. `2021-07`.
+/// The names don't depend on the default year, so they match the `[[bin]]` entries of `Cargo.toml`
+/// whatever `AOC_YEAR` and `aoc.toml` are set to.
+pub fn binary_name(year: u16, day: u8) -> String {
+    match year == TEMPLATE_YEAR {
+        true => format!("{:02}", day),
+        false => format!("{}-{:02}", year, day),
+    }
+}
+
+/// The `inputs` or `examples` directory of a year, `src/<year>/<folder>` unless it is moved in
+/// the `[dirs]` section of `aoc.toml`. Inputs are read from `$AOC_INPUT_DIR` if it is set.
+pub fn data_dir(year: u16, folder: &str) -> PathBuf {
+    let config = template::config::config();
+    let dir = match folder {
+        "inputs" => match env::var_os(INPUT_DIR_ENV) {
+            Some(dir) => return PathBuf::from(dir),
+            None => config.inputs_dir.as_deref(),
+        },
+        "examples" => config.examples_dir.as_deref(),
+        _ => None,
+    };
+    match dir {
+        Some(dir) => crate_dir().join(dir.replace("{year}", &year.to_string())),
+        None => year_dir(year).join(folder),
+    }
+}
+
+/// The directory with the recorded answers and timing history of a year's puzzle inputs:
+/// `src/<year>`, or the inputs directory itself if the inputs are moved, so that answers and
+/// timings of one set of inputs are never checked against another.
+pub fn records_dir(year: u16) -> PathBuf {
+    let inputs = data_dir(year, "inputs");
+    match inputs == year_dir(year).join("inputs") {
+        true => year_dir(year),
+        false => inputs,
+    }
+}
+
+pub fn file_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    data_dir(year, folder).join(format!("{:02}.txt", day))
+}
+
+/// Failure to read an input or example file.
+#[derive(Debug)]
+pub struct ReadFileError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not read file \"{}\": {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Reads `<day>.txt` from the [`data_dir`] of a year, e.g. `src/2022/inputs/01.txt`.
+pub fn read_file(year: u16, folder: &str, day: u8) -> Result<String, ReadFileError> {
+    let path = file_path(year, folder, day);
+    fs::read_to_string(&path).map_err(|source| ReadFileError { path, source })
+}
+
+/// Reads the input selected by `--input` or `--example` for a day.
+pub fn read_input(
+    year: u16,
+    day: u8,
+    input: &template::args::Input,
+) -> Result<String, ReadFileError> {
+    use template::args::Input;
+
+    match input {
+        Input::Puzzle => read_file(year, "inputs", day),
+        Input::Example => read_file(year, "examples", day),
+        Input::File(path) => fs::read_to_string(path).map_err(|source| ReadFileError {
+            path: path.clone(),
+            source,
+        }),
+        Input::Stdin => {
+            let mut buffer = String::new();
+            io::stdin()
+                .read_to_string(&mut buffer)
+                .map(|_| buffer)
+                .map_err(|source| ReadFileError {
+                    path: PathBuf::from("<stdin>"),
+                    source,
+                })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +335,33 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    #[should_panic(expected = "row 1, column 0: expected '#', got '.'")]
+    fn test_assert_grid_eq() {
+        assert_grid_eq!("#.\n.#", "#.\n.#");
+        assert_grid_eq!(String::from("#.\n.#"), "#.\n##");
+    }
+
+    #[test]
+    fn test_binary_name() {
+        assert_eq!(binary_name(TEMPLATE_YEAR, 7), "07");
+        assert_eq!(binary_name(2021, 7), "2021-07");
+    }
+
+    #[test]
+    fn test_binary_name_ignores_aoc_year() {
+        // the `[[bin]]` names in `Cargo.toml` don't change with the default year.
+        env::set_var(YEAR_ENV, "2021");
+        let names = (default_year(), binary_name(2021, 3), binary_name(2022, 3));
+        env::remove_var(YEAR_ENV);
+        assert_eq!(names, (2021, "2021-03".to_string(), "03".to_string()));
+    }
+
+    #[test]
+    fn test_read_file_error() {
+        let error = read_file(2022, "examples", 0).unwrap_err();
+        assert!(error.path.ends_with("src/2022/examples/00.txt"));
+        assert!(error
+            .to_string()
+            .starts_with(&format!("could not read file \"{}\"", error.path.display())));
     }
 }
@@ -2,45 +2,202 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::template::args::{self, Format, Input, Options};
+use advent_of_code::template::config::{config, load_config};
+use advent_of_code::template::history::{self, History};
+use advent_of_code::template::readme;
+use advent_of_code::template::report::{self, ReportFormat};
+use advent_of_code::template::runner::AllOptions;
+use advent_of_code::template::summary::{self, Summary};
+use advent_of_code::template::worker;
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process;
+
+mod days;
+
+struct Args {
+    options: Options,
+    all_options: AllOptions,
+    /// Write the results to the benchmarking table in `README.md`.
+    readme: bool,
+    /// Compare the timings with previous runs.
+    compare: bool,
+    /// Write a report of the results to a file.
+    report: Option<(ReportFormat, PathBuf)>,
+    /// Only run this day, in the child process started for it by `run_all`.
+    run_day: Option<u8>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let config = config();
+    let mut args = args::arguments();
+    let mut parsed = Args {
+        options: args::parse_options(&mut args)?,
+        all_options: AllOptions {
+            jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+            year: args
+                .opt_value_from_str(["-y", "--year"])?
+                .unwrap_or_else(advent_of_code::default_year),
+            timeout: args
+                .opt_value_from_fn("--timeout", args::parse_seconds)?
+                .or(config.timeout),
+            day_timeouts: config
+                .day_timeouts
+                .clone()
+                .into_iter()
+                .chain(args.values_from_fn("--day-timeout", args::parse_day_timeout)?)
+                .collect(),
+            days: Vec::new(),
+            only_solved: args.contains("--only-solved"),
+        },
+        readme: args.contains("--readme"),
+        compare: args.contains("--compare"),
+        report: match (
+            args.opt_value_from_str("--report")?,
+            args.opt_value_from_str("--out")?,
+        ) {
+            (Some(format), Some(path)) => Some((format, path)),
+            (None, None) => None,
+            _ => {
+                return Err(args::invalid_argument(
+                    "--report",
+                    "has to be passed together with --out",
+                ))
+            }
+        },
+        run_day: args.opt_value_from_str(worker::RUN_DAY_FLAG)?,
+    };
+
+    // skipping days after the last one does no harm, so `--skip` allows every day.
+    let skip = args
+        .values_from_fn("--skip", |s| args::parse_days(s, 25))?
+        .concat();
+    parsed.all_options.days = select_days(args.finish(), &skip, config.days.unwrap_or(25))?;
+    Ok(parsed)
+}
+
+/// The days selected by the free arguments, e.g. `cargo all -- 3..7 12`, without skipped days.
+/// Selects every day up to `last_day` if there are no free arguments, and fails if a selected
+/// day is after it.
+fn select_days(
+    selectors: Vec<OsString>,
+    skip: &[u8],
+    last_day: u8,
+) -> Result<Vec<u8>, pico_args::Error> {
+    let mut days = BTreeSet::new();
+
+    for selector in &selectors {
+        let selector = selector.to_str().ok_or(pico_args::Error::NonUtf8Argument)?;
+        if selector.starts_with('-') {
+            return Err(args::invalid_argument(selector, "unknown flag"));
+        }
+        let selected = args::parse_days(selector, last_day)
+            .map_err(|e| args::invalid_argument(selector, &e))?;
+        if let Some(day) = selected.iter().find(|day| **day > last_day) {
+            return Err(args::invalid_argument(
+                selector,
+                &format!("day {} is after the last day, {}", day, last_day),
+            ));
+        }
+        days.extend(selected);
+    }
+    if selectors.is_empty() {
+        days.extend(1..=last_day);
+    }
+
+    Ok(days.into_iter().filter(|day| !skip.contains(day)).collect())
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    if let Err(e) = load_config() {
+        eprintln!("Failed to load the settings: {}", e);
+        process::exit(1);
+    }
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    if let Some(day) = args.run_day {
+        worker::run(&days::all(), args.all_options.year, day, &args.options);
+        return;
+    }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    if matches!(args.options.input, Input::File(_) | Input::Stdin) {
+        eprintln!(
+            "--input only works when solving a single day, use --example to run all examples."
+        );
+        process::exit(1);
+    }
+    if args.options.all_inputs {
+        eprintln!("--all-inputs only works when solving a single day.");
+        process::exit(1);
+    }
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    let year = args.all_options.year;
+    let run = history::now();
+    let results =
+        advent_of_code::template::runner::run_all(&days::all(), &args.options, &args.all_options);
+
+    // only timings of the puzzle inputs are comparable between runs, and only if the days
+    // didn't share the CPU.
+    if args.options.input == Input::Puzzle && args.all_options.jobs <= 1 {
+        let entries = history::entries(run, history::mode(&args.options), &results);
+        let previous = match args.compare {
+            true => match History::load(year) {
+                Ok(history) => Some(history),
+                Err(e) => {
+                    eprintln!("Failed to load the timing history: {}", e);
+                    process::exit(1);
                 }
+            },
+            false => None,
+        };
+        if let Err(e) = history::append(year, &entries) {
+            eprintln!("Failed to update the timing history: {}", e);
+            process::exit(1);
+        }
+        if let (Some(previous), Format::Text) = (previous, args.options.format) {
+            history::print_comparisons(&history::compare(&previous, &entries));
+        }
+    } else if args.compare {
+        eprintln!("--compare only works for days on the puzzle input that run one at a time.");
+    }
+
+    if args.readme {
+        if let Err(e) = readme::update_readme(year, &results) {
+            eprintln!("Failed to update the readme: {}", e);
+            process::exit(1);
+        }
+        if args.options.format == Format::Text {
+            println!(
+                "📝 Updated the benchmarking table in \"{}\".",
+                readme::readme_path().display()
             );
+        }
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
-        })
-        .sum();
+    if let Some((format, path)) = &args.report {
+        if let Err(e) = report::write(*format, path, year, &results) {
+            eprintln!("Failed to write the report: {}", e);
+            process::exit(1);
+        }
+        if args.options.format == Format::Text {
+            println!("📝 Wrote the report to \"{}\".", path.display());
+        }
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    let summary = Summary::new(&results);
+    if args.options.format == Format::Text {
+        summary::print_summary(&summary);
+    }
+    if summary.failed() {
+        process::exit(1);
+    }
 }
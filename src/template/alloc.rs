@@ -0,0 +1,141 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! A global allocator that counts heap allocations of the current thread.
+//! It is only installed when the `alloc-stats` feature is enabled.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Wraps the system allocator and keeps per-thread counters, so that parts
+/// running in parallel (`cargo all -- --jobs <n>`) don't skew each other's numbers.
+pub struct CountingAllocator;
+
+#[derive(Clone, Copy, Default)]
+struct Counters {
+    allocations: usize,
+    bytes: usize,
+    live: usize,
+    peak: usize,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn update(func: impl FnOnce(&mut Counters)) {
+    // the thread local is gone while a thread shuts down, its allocations are not counted.
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        func(&mut current);
+        counters.set(current);
+    });
+}
+
+fn on_alloc(size: usize) {
+    update(|c| {
+        c.allocations += 1;
+        c.bytes += size;
+        c.live += size;
+        c.peak = c.peak.max(c.live);
+    });
+}
+
+fn on_dealloc(size: usize) {
+    update(|c| c.live = c.live.saturating_sub(size));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        on_alloc(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        on_alloc(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        on_dealloc(layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        on_dealloc(layout.size());
+        on_alloc(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Heap usage of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// Highest number of bytes that were allocated at the same time, on top of what was
+    /// allocated before the part started.
+    pub peak_bytes: usize,
+}
+
+/// Calls `func` and counts the allocations it makes on the current thread.
+/// The stats are `None` if the `alloc-stats` feature is disabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (func(), None);
+    }
+
+    let mut start = Counters::default();
+    update(|c| {
+        c.peak = c.live;
+        start = *c;
+    });
+
+    let output = func();
+
+    let mut end = start;
+    update(|c| end = *c);
+    let stats = AllocStats {
+        allocations: end.allocations - start.allocations,
+        bytes: end.bytes - start.bytes,
+        peak_bytes: end.peak.saturating_sub(start.live),
+    };
+    (output, Some(stats))
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.50 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+}
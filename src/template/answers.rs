@@ -0,0 +1,151 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::template::toml::{self, Document, Value};
+use crate::template::Answer;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+const HEADER: &str = "# Answers for the puzzle inputs of this year.\n# Written by `cargo solve <day> -- --record` and `cargo all -- --record`.\n\n";
+
+/// How a part's answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+/// Recorded answers, keyed by day and part.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, u8), Answer>);
+
+pub fn answers_path(year: u16) -> PathBuf {
+    crate::records_dir(year).join("answers.toml")
+}
+
+fn part_key(part: u8) -> &'static str {
+    match part {
+        1 => "part_one",
+        _ => "part_two",
+    }
+}
+
+impl Answers {
+    /// Loads `answers.toml` from the [`crate::records_dir`]. A missing file yields no recorded answers.
+    pub fn load(year: u16) -> Result<Self, String> {
+        let path = answers_path(year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let document = toml::parse(contents)?;
+        let mut answers = Self::default();
+
+        for (table, values) in document {
+            let day = match table.strip_prefix("day").and_then(|d| d.parse().ok()) {
+                Some(day) => day,
+                None => continue,
+            };
+            for part in [1, 2] {
+                let answer = match values.get(part_key(part)) {
+                    Some(Value::Integer(n)) => Answer::Number(*n),
+                    Some(Value::String(s)) => Answer::Text(s.clone()),
+                    _ => continue,
+                };
+                answers.0.insert((day, part), answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected.to_string() == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: Answer) {
+        self.0.insert((day, part), answer);
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut document = Document::new();
+        for ((day, part), answer) in &self.0 {
+            let value = match answer {
+                Answer::Number(n) => Value::Integer(*n),
+                Answer::Text(s) => Value::String(s.clone()),
+            };
+            document
+                .entry(format!("day{:02}", day))
+                .or_default()
+                .insert(part_key(*part).to_string(), value);
+        }
+        format!("{}{}", HEADER, toml::to_string(&document))
+    }
+
+    pub fn save(&self, year: u16) -> Result<(), String> {
+        let path = answers_path(year);
+        fs::write(&path, self.to_toml())
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answers =
+            Answers::parse("[day01]\npart_one = 24000\n\n[day05]\npart_two = \"MCD\"\n").unwrap();
+
+        assert_eq!(
+            answers.check(1, 1, &Answer::Number(24000)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(1, 1, &Answer::Number(24001)),
+            Verdict::Wrong {
+                expected: Answer::Number(24000)
+            }
+        );
+        assert_eq!(
+            answers.check(1, 2, &Answer::Number(45000)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.check(5, 2, &Answer::Text("MCD".to_string())),
+            Verdict::Correct
+        );
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers::default();
+        answers.record(10, 2, Answer::Text("##..\n..##".to_string()));
+        answers.record(1, 1, Answer::Number(24000));
+
+        let toml = answers.to_toml();
+        assert!(
+            toml.ends_with("[day01]\npart_one = 24000\n\n[day10]\npart_two = \"##..\\n..##\"\n")
+        );
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+    }
+}
@@ -0,0 +1,308 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::template::config::config;
+use std::env;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Output format of `cargo solve` and `cargo all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format \"{}\", expected text or json", s)),
+        }
+    }
+}
+
+/// Input that solutions run on.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Input {
+    /// `src/<year>/inputs/NN.txt`, unless moved in `aoc.toml` or with `$AOC_INPUT_DIR`.
+    #[default]
+    Puzzle,
+    /// `src/<year>/examples/NN.txt` unless moved in `aoc.toml`, selected with `--example`.
+    Example,
+    /// A file selected with `--input <path>`.
+    File(PathBuf),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+}
+
+/// Options shared by every solution binary and `cargo all`,
+/// e.g. `cargo solve 01 -- --format json --bench 100 --record`.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub format: Format,
+    /// Number of timed iterations per part. Runs each part once if not set.
+    pub bench: Option<usize>,
+    /// Store the answers of this run in `src/<year>/answers.toml`.
+    pub record: bool,
+    pub input: Input,
+    /// Only run this part, selected with `--part 1` or `--part 2`.
+    pub part: Option<u8>,
+    /// Run on every input in `src/<year>/inputs/NN/`, selected with `--all-inputs`.
+    pub all_inputs: bool,
+}
+
+impl Options {
+    /// The parts that run, in order.
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+pub fn parse_options(args: &mut pico_args::Arguments) -> Result<Options, pico_args::Error> {
+    let example = args.contains("--example");
+    let path: Option<PathBuf> =
+        args.opt_value_from_os_str("--input", |s| Ok::<_, String>(PathBuf::from(s)))?;
+
+    let input = match (example, path) {
+        (true, Some(_)) => {
+            return Err(invalid_argument(
+                "--example",
+                "can't be combined with --input",
+            ))
+        }
+        (true, None) => Input::Example,
+        (false, Some(path)) if path.as_os_str() == "-" => Input::Stdin,
+        (false, Some(path)) => Input::File(path),
+        (false, None) => Input::Puzzle,
+    };
+
+    let record = args.contains("--record");
+    if record && input != Input::Puzzle {
+        return Err(invalid_argument(
+            "--record",
+            "only works with the puzzle input",
+        ));
+    }
+
+    let all_inputs = args.contains("--all-inputs");
+    if all_inputs && (input != Input::Puzzle || record) {
+        return Err(invalid_argument(
+            "--all-inputs",
+            "can't be combined with --example, --input or --record",
+        ));
+    }
+
+    Ok(Options {
+        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        bench: args.opt_value_from_str("--bench")?,
+        record,
+        input,
+        part: args.opt_value_from_fn("--part", parse_part)?,
+        all_inputs,
+    })
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("\"{}\" is not a part, expected 1 or 2", s)),
+    }
+}
+
+pub(crate) fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", s)),
+    }
+}
+
+/// Parses a comma-separated list of days and day ranges, e.g. `1,3..7,20..`.
+/// Ranges work like in rust: `3..7` excludes day 7, `3..=7` includes it.
+/// A missing start or end means the first day or `last_day`.
+pub fn parse_days(s: &str, last_day: u8) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+
+    for item in s.split(',') {
+        let (start, end) = match item.split_once("..") {
+            Some((start, end)) => {
+                let start = match start {
+                    "" => 1,
+                    start => parse_day(start)?,
+                };
+                let end = match (end.strip_prefix('='), end) {
+                    (Some(end), _) => parse_day(end)?,
+                    (None, "") => last_day,
+                    (None, end) => parse_day(end)? - 1,
+                };
+                (start, end)
+            }
+            None => {
+                let day = parse_day(item)?;
+                (day, day)
+            }
+        };
+        if start > end {
+            return Err(format!("\"{}\" is an empty range", item));
+        }
+        days.extend(start..=end);
+    }
+
+    Ok(days)
+}
+
+/// An error for a flag that can't be used the way it was passed.
+pub fn invalid_argument(flag: &str, cause: &str) -> pico_args::Error {
+    pico_args::Error::Utf8ArgumentParsingFailed {
+        value: flag.to_string(),
+        cause: cause.to_string(),
+    }
+}
+
+/// Parses a number of seconds, e.g. `10` or `0.5`.
+pub fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("\"{}\" is not a number of seconds", s))
+}
+
+/// Parses a time limit for a single day, e.g. `12=30` for 30 seconds on day 12.
+pub fn parse_day_timeout(s: &str) -> Result<(u8, Duration), String> {
+    let (day, seconds) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected <day>=<seconds>, got \"{}\"", s))?;
+    Ok((parse_day(day)?, parse_seconds(seconds)?))
+}
+
+/// Inserts the number of iterations after a `--bench` that isn't followed by one.
+fn with_bench_iterations(mut args: Vec<OsString>, iterations: usize) -> Vec<OsString> {
+    let mut index = 0;
+    while index < args.len() {
+        let has_number = args
+            .get(index + 1)
+            .and_then(|next| next.to_str())
+            .is_some_and(|next| next.parse::<usize>().is_ok());
+        if args[index] == "--bench" && !has_number {
+            args.insert(index + 1, iterations.to_string().into());
+        }
+        index += 1;
+    }
+    args
+}
+
+/// The arguments of the running binary. A `--bench` without a number of iterations
+/// runs the number of iterations set in `aoc.toml`.
+pub fn arguments() -> pico_args::Arguments {
+    let args = env::args_os().skip(1).collect();
+    match config().bench_iterations {
+        Some(iterations) => pico_args::Arguments::from_vec(with_bench_iterations(args, iterations)),
+        None => pico_args::Arguments::from_vec(args),
+    }
+}
+
+/// Options of the running solution binary, parsed once on first use.
+pub fn solve_options() -> &'static Options {
+    static OPTIONS: OnceLock<Options> = OnceLock::new();
+    OPTIONS.get_or_init(|| match parse_options(&mut arguments()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, pico_args::Error> {
+        let args = args.iter().map(Into::into).collect();
+        parse_options(&mut pico_args::Arguments::from_vec(args))
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse(&[]).unwrap().input, Input::Puzzle);
+        assert_eq!(parse(&["--example"]).unwrap().input, Input::Example);
+        assert_eq!(parse(&["--input", "-"]).unwrap().input, Input::Stdin);
+        assert_eq!(
+            parse(&["--input", "other.txt"]).unwrap().input,
+            Input::File(PathBuf::from("other.txt"))
+        );
+        assert!(parse(&["--example", "--input", "-"]).is_err());
+        assert!(parse(&["--example", "--record"]).is_err());
+        assert!(parse(&["--all-inputs"]).unwrap().all_inputs);
+        assert!(parse(&["--all-inputs", "--example"]).is_err());
+        assert!(parse(&["--all-inputs", "--record"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse(&[]).unwrap().parts(), vec![1, 2]);
+        assert_eq!(parse(&["--part", "2"]).unwrap().parts(), vec![2]);
+        assert!(parse(&["--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("12", 25), Ok(vec![12]));
+        assert_eq!(parse_days("1,3..6,4", 25), Ok(vec![1, 3, 4, 5, 4]));
+        assert_eq!(parse_days("3..=5", 25), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("..3", 25), Ok(vec![1, 2]));
+        assert_eq!(parse_days("24..", 25), Ok(vec![24, 25]));
+        assert_eq!(parse_days("22..", 24), Ok(vec![22, 23, 24]));
+        assert!(parse_days("0", 25).is_err());
+        assert!(parse_days("26", 25).is_err());
+        assert!(parse_days("7..3", 25).is_err());
+        assert!(parse_days("5..5", 25).is_err());
+        assert!(parse_days("a", 25).is_err());
+    }
+
+    #[test]
+    fn test_with_bench_iterations() {
+        let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+        assert_eq!(
+            with_bench_iterations(args(&["--bench"]), 100),
+            args(&["--bench", "100"])
+        );
+        assert_eq!(
+            with_bench_iterations(args(&["--bench", "--record"]), 100),
+            args(&["--bench", "100", "--record"])
+        );
+        assert_eq!(
+            with_bench_iterations(args(&["--bench", "10", "3..7"]), 100),
+            args(&["--bench", "10", "3..7"])
+        );
+    }
+
+    #[test]
+    fn test_parse_day_timeout() {
+        assert_eq!(
+            parse_day_timeout("12=30"),
+            Ok((12, Duration::from_secs(30)))
+        );
+        assert_eq!(
+            parse_day_timeout("7=0.5"),
+            Ok((7, Duration::from_millis(500)))
+        );
+        assert!(parse_day_timeout("12").is_err());
+        assert!(parse_day_timeout("0=5").is_err());
+        assert!(parse_day_timeout("99=5").is_err());
+        assert!(parse_day_timeout("12=-1").is_err());
+    }
+}
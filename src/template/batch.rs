@@ -0,0 +1,362 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Runs a day on every input in `src/<year>/inputs/NN/`, e.g. the inputs of several accounts,
+//! to check that a solution doesn't rely on the quirks of a single input.
+//!
+//! Every `<name>.txt` can have a `<name>.toml` next to it with its expected answers:
+//!
+//! ```toml
+//! part_one = 24000
+//! part_two = 45000
+//! ```
+use crate::template::answers::Answers;
+use crate::template::args::{Format, Options};
+use crate::template::examples::{expected_answer, Example};
+use crate::template::readme::{elapsed, format_time, status};
+use crate::template::runner::{
+    check_part, json_parse_record, json_record, json_string, Failure, ParseResult, PartResult,
+    StageResult, Timer,
+};
+use crate::template::summary::icon;
+use crate::template::toml::{self, Table};
+use crate::template::Solution;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// The directory with the inputs of a day, `src/<year>/inputs/NN/` or `$AOC_INPUT_DIR/NN/`.
+pub fn inputs_dir(year: u16, day: u8) -> PathBuf {
+    crate::file_path(year, "inputs", day).with_extension("")
+}
+
+/// Loads every `.txt` file in `dir` in the order of their names, together with the expected
+/// answers in the `.toml` file of the same name.
+pub fn load(dir: &Path) -> Result<Vec<Example>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("could not read {}: {}", dir.display(), e))?;
+    let mut paths = Vec::new();
+
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("could not read {}: {}", dir.display(), e))?
+            .path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths.iter().map(|path| load_input(path)).collect()
+}
+
+fn load_input(path: &Path) -> Result<Example, String> {
+    let input = fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+
+    let answers_path = path.with_extension("toml");
+    let answers = match fs::read_to_string(&answers_path) {
+        Ok(contents) => parse_answers(&contents),
+        Err(_) => Ok(Table::new()),
+    };
+    let answer = |key: &str| {
+        answers
+            .clone()
+            .and_then(|answers| expected_answer(&answers, key))
+            .map_err(|e| format!("{}: {}", answers_path.display(), e))
+    };
+
+    Ok(Example {
+        name: path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned(),
+        part_one: answer("part_one")?,
+        part_two: answer("part_two")?,
+        input,
+    })
+}
+
+/// The keys at the top of an answers file, before any table.
+fn parse_answers(contents: &str) -> Result<Table, String> {
+    let mut document = toml::parse(contents)?;
+    Ok(document.remove("").unwrap_or_default())
+}
+
+/// The results of a day on a single input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputResult {
+    pub name: String,
+    pub parse: Option<ParseResult>,
+    pub parts: Vec<PartResult>,
+}
+
+impl InputResult {
+    pub fn part(&self, part: u8) -> Option<&PartResult> {
+        self.parts.iter().find(|result| result.part == part)
+    }
+}
+
+/// The expected answers of an input, to check its results like those of the puzzle input.
+fn expected_answers(day: u8, input: &Example) -> Answers {
+    let mut answers = Answers::default();
+    for part in [1, 2] {
+        if let Some(answer) = input.expected(part) {
+            answers.record(day, part, answer.clone());
+        }
+    }
+    answers
+}
+
+/// Runs a day on every input, one after another, and checks the answers against the expected ones.
+pub fn run(solution: &dyn Solution, inputs: &[Example], options: &Options) -> Vec<InputResult> {
+    let mut results = Vec::new();
+
+    for input in inputs {
+        let mut answers = expected_answers(solution.day(), input);
+        let mut result = InputResult {
+            name: input.name.clone(),
+            parse: None,
+            parts: Vec::new(),
+        };
+        let on_result = |stage| match stage {
+            StageResult::Parse(parse) => result.parse = Some(parse),
+            StageResult::Part(mut part) => {
+                check_part(&mut part, &mut answers, false);
+                result.parts.push(part);
+            }
+        };
+        solution.run(
+            &input.input,
+            &mut Timer::new(solution.day(), options, on_result),
+        );
+        results.push(result);
+    }
+
+    results
+}
+
+/// Escapes the pipes of a table cell, and turns line breaks into `<br>`.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// An answer as code, with a code span per line, since line breaks can't be part of one.
+fn answer_cell(result: Option<&PartResult>) -> String {
+    match result.and_then(|result| result.answer.as_ref()) {
+        Some(answer) => {
+            let lines: Vec<String> = answer
+                .to_string()
+                .lines()
+                .map(|line| format!("`{}`", escape_cell(line)))
+                .collect();
+            format!("{} {}", lines.join("<br>"), status(result))
+        }
+        None => status(result).to_string(),
+    }
+}
+
+/// Renders a markdown table with the answers and timings of every input.
+pub fn table(results: &[InputResult]) -> String {
+    let mut out = String::from(
+        "| Input | Part 1 | Part 2 | Parse | Time part 1 | Time part 2 | Total |\n| :--- | :---: | :---: | ---: | ---: | ---: | ---: |\n",
+    );
+
+    for result in results {
+        let times = [
+            result.parse.as_ref().map(|parse| parse.elapsed),
+            elapsed(result.part(1)),
+            elapsed(result.part(2)),
+        ];
+        out += &format!(
+            "| {} | {} | {} | {} | {} | {} | {} |\n",
+            escape_cell(&result.name),
+            answer_cell(result.part(1)),
+            answer_cell(result.part(2)),
+            format_time(times[0]),
+            format_time(times[1]),
+            format_time(times[2]),
+            format_time(Some(times.iter().flatten().sum())),
+        );
+    }
+
+    out
+}
+
+/// Failed parts by input and part, in input order.
+pub fn failures(results: &[InputResult]) -> Vec<(&str, u8, Failure)> {
+    results
+        .iter()
+        .flat_map(|result| {
+            result.parts.iter().filter_map(|part| {
+                part.failure()
+                    .map(|failure| (result.name.as_str(), part.part, failure))
+            })
+        })
+        .collect()
+}
+
+/// Runs a day on every input in [`inputs_dir`] and prints the results, selected with
+/// `cargo solve <day> -- --all-inputs`. Exits with a non-zero status if a part failed.
+pub fn solve_inputs(solution: &dyn Solution, options: &Options) {
+    let dir = inputs_dir(solution.year(), solution.day());
+    let inputs = match load(&dir) {
+        Ok(inputs) if inputs.is_empty() => {
+            eprintln!("There are no inputs in \"{}\".", dir.display());
+            process::exit(1);
+        }
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Failed to load the inputs: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let results = run(solution, &inputs, options);
+    let failures = failures(&results);
+
+    match options.format {
+        Format::Text => {
+            print!("{}", table(&results));
+            for (name, part, failure) in &failures {
+                println!("{} {}, part {}: {}", icon(failure), name, part, failure);
+            }
+        }
+        Format::Json => {
+            let name = |result: &InputResult| json_string(&result.name);
+            for result in &results {
+                if let Some(parse) = &result.parse {
+                    println!(
+                        "{{\"input\":{},{}",
+                        name(result),
+                        &json_parse_record(parse)[1..]
+                    );
+                }
+                for part in &result.parts {
+                    println!("{{\"input\":{},{}", name(result), &json_record(part)[1..]);
+                }
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::answers::Verdict;
+    use crate::template::Answer;
+    use std::time::Duration;
+
+    fn part(part: u8, answer: Option<i128>, micros: u64, verdict: Verdict) -> PartResult {
+        PartResult {
+            answer: answer.map(Answer::Number),
+            elapsed: Duration::from_micros(micros),
+            verdict: answer.map(|_| verdict),
+            ..PartResult::unsolved(1, part)
+        }
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# alice\npart_one = 24000\npart_two = \"MCD\"\n").unwrap();
+        assert_eq!(
+            expected_answer(&answers, "part_one"),
+            Ok(Some(Answer::Number(24000)))
+        );
+        assert_eq!(
+            expected_answer(&answers, "part_two"),
+            Ok(Some(Answer::Text("MCD".to_string())))
+        );
+        assert_eq!(parse_answers("").unwrap(), Table::new());
+    }
+
+    #[test]
+    fn test_expected_answers() {
+        let input = Example {
+            name: "alice".to_string(),
+            input: String::new(),
+            part_one: Some(Answer::Number(24000)),
+            part_two: None,
+        };
+        let answers = expected_answers(1, &input);
+        assert_eq!(
+            answers.check(1, 1, &Answer::Number(24000)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(1, 1, &Answer::Number(1)),
+            Verdict::Wrong {
+                expected: Answer::Number(24000)
+            }
+        );
+        assert_eq!(answers.check(1, 2, &Answer::Number(1)), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_table() {
+        let results = [
+            InputResult {
+                name: "alice".to_string(),
+                parse: None,
+                parts: vec![
+                    part(1, Some(24000), 40, Verdict::Correct),
+                    part(2, Some(45000), 60, Verdict::Unknown),
+                ],
+            },
+            InputResult {
+                name: "bob".to_string(),
+                parse: None,
+                parts: vec![
+                    part(
+                        1,
+                        Some(1),
+                        100,
+                        Verdict::Wrong {
+                            expected: Answer::Number(2),
+                        },
+                    ),
+                    PartResult::panicked(1, 2, "oops".to_string()),
+                ],
+            },
+        ];
+
+        assert_eq!(
+            table(&results),
+            "| Input | Part 1 | Part 2 | Parse | Time part 1 | Time part 2 | Total |\n\
+             | :--- | :---: | :---: | ---: | ---: | ---: | ---: |\n\
+             | alice | `24000` ✅ | `45000` ❔ | - | `40.00µs` | `60.00µs` | `100.00µs` |\n\
+             | bob | `1` ❌ | 💥 | - | `100.00µs` | - | `100.00µs` |\n"
+        );
+        assert_eq!(
+            failures(&results)
+                .iter()
+                .map(|(name, part, _)| (*name, *part))
+                .collect::<Vec<_>>(),
+            vec![("bob", 1), ("bob", 2)]
+        );
+    }
+
+    #[test]
+    fn test_table_escapes_cells() {
+        let results = [InputResult {
+            name: "a|b".to_string(),
+            parse: None,
+            parts: vec![PartResult {
+                answer: Some(Answer::Text("#.|\n.#.".to_string())),
+                verdict: Some(Verdict::Correct),
+                ..PartResult::unsolved(10, 2)
+            }],
+        }];
+
+        assert_eq!(
+            table(&results).lines().nth(2),
+            Some("| a\\|b | - | `#.\\|`<br>`.#.` ✅ | - | - | `0.00ns` | `0.00ns` |")
+        );
+    }
+}
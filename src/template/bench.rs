@@ -0,0 +1,119 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, Instant};
+
+/// Summary statistics of repeated timings of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    /// Samples outside 1.5 times the interquartile range.
+    pub mild_outliers: usize,
+    /// Samples outside 3 times the interquartile range.
+    pub severe_outliers: usize,
+}
+
+/// Number of untimed runs before measuring `iterations` runs.
+pub fn warmup_runs(iterations: usize) -> usize {
+    (iterations / 10).max(1)
+}
+
+/// Calls `func` for warmup, then times `iterations` calls. Returns the last result.
+pub fn bench<T>(mut func: impl FnMut() -> T, iterations: usize) -> (T, Stats) {
+    for _ in 0..warmup_runs(iterations) {
+        func();
+    }
+
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+    for _ in 0..iterations.max(1) {
+        let timer = Instant::now();
+        result = Some(func());
+        samples.push(timer.elapsed());
+    }
+
+    (result.unwrap(), stats(&samples))
+}
+
+/// Computes statistics for a non-empty list of samples.
+pub fn stats(samples: &[Duration]) -> Stats {
+    let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = sorted.len() as f64;
+    let mean = sorted.iter().sum::<f64>() / n;
+    let variance = sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+
+    let q1 = percentile(&sorted, 0.25);
+    let q3 = percentile(&sorted, 0.75);
+    let iqr = q3 - q1;
+    let outside = |factor: f64| {
+        sorted
+            .iter()
+            .filter(|s| **s < q1 - factor * iqr || **s > q3 + factor * iqr)
+            .count()
+    };
+    let severe_outliers = outside(3.0);
+
+    Stats {
+        samples: sorted.len(),
+        min: nanos(sorted[0]),
+        median: nanos(percentile(&sorted, 0.5)),
+        mean: nanos(mean),
+        stddev: nanos(variance.sqrt()),
+        mild_outliers: outside(1.5) - severe_outliers,
+        severe_outliers,
+    }
+}
+
+fn nanos(value: f64) -> Duration {
+    Duration::from_nanos(value.round() as u64)
+}
+
+/// Linearly interpolated percentile of sorted samples.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [10, 12, 11, 13, 12, 11, 10, 12, 11, 100]
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect();
+        let stats = stats(&samples);
+
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.min, Duration::from_millis(10));
+        assert_eq!(
+            stats.median,
+            Duration::from_millis(11) + Duration::from_micros(500)
+        );
+        assert_eq!(
+            stats.mean,
+            Duration::from_millis(20) + Duration::from_micros(200)
+        );
+        assert_eq!(stats.mild_outliers, 0);
+        assert_eq!(stats.severe_outliers, 1);
+    }
+
+    #[test]
+    fn test_bench() {
+        let (result, stats) = bench(|| 42, 20);
+        assert_eq!(result, 42);
+        assert_eq!(stats.samples, 20);
+        assert!(stats.min <= stats.median);
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Settings of the template, read from `aoc.toml` at the root of the crate.
+//! Every setting is optional, command line flags take precedence.
+//!
+//! ```toml
+//! year = 2022
+//! days = 25
+//!
+//! [dirs]
+//! inputs = "src/{year}/inputs"
+//! examples = "src/{year}/examples"
+//!
+//! [bench]
+//! iterations = 100
+//!
+//! [timeouts]
+//! default = 10
+//! day11 = 60
+//!
+//! [download]
+//! session_file = "~/.adventofcode.session"
+//! ```
+use crate::template::args::{parse_day, parse_seconds};
+use crate::template::toml::{self, Value};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Config {
+    /// The year to work on unless `--year` is passed.
+    pub year: Option<u16>,
+    /// The number of puzzle days, days after it are not run by `cargo all`.
+    pub days: Option<u8>,
+    /// Directory of the puzzle inputs, relative to the crate root. `{year}` is replaced with the year.
+    pub inputs_dir: Option<String>,
+    /// Directory of the examples, relative to the crate root. `{year}` is replaced with the year.
+    pub examples_dir: Option<String>,
+    /// Timed iterations per part when `--bench` is passed without a number.
+    pub bench_iterations: Option<usize>,
+    /// Time limit of `cargo all` for every day.
+    pub timeout: Option<Duration>,
+    /// Time limits of single days, overriding `timeout`.
+    pub day_timeouts: BTreeMap<u8, Duration>,
+    /// File with the session cookie of adventofcode.com, used by `cargo download`.
+    pub session_file: Option<PathBuf>,
+}
+
+pub fn config_path() -> PathBuf {
+    crate::crate_dir().join("aoc.toml")
+}
+
+fn integer<T: TryFrom<i128>>(name: &str, value: &Value) -> Result<T, String> {
+    match value {
+        Value::Integer(n) => T::try_from(*n).map_err(|_| format!("`{}` is out of range", name)),
+        _ => Err(format!("`{}` is not a number", name)),
+    }
+}
+
+fn string(name: &str, value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        _ => Err(format!("`{}` is not a string", name)),
+    }
+}
+
+/// A number of seconds, either as integer or as string, e.g. `10` or `"0.5"`.
+fn seconds(name: &str, value: &Value) -> Result<Duration, String> {
+    match value {
+        Value::Integer(n) => parse_seconds(&n.to_string()),
+        Value::String(s) => parse_seconds(s),
+        Value::Boolean(_) => Err(format!("`{}` is not a number of seconds", name)),
+    }
+    .map_err(|e| format!("`{}`: {}", name, e))
+}
+
+/// A path relative to the crate root, `~/` is replaced with the home directory.
+fn path(name: &str, value: &Value) -> Result<PathBuf, String> {
+    let path = string(name, value)?;
+    Ok(match (path.strip_prefix("~/"), env::var_os("HOME")) {
+        (Some(path), Some(home)) => PathBuf::from(home).join(path),
+        _ => crate::crate_dir().join(path),
+    })
+}
+
+impl Config {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let document = toml::parse(contents)?;
+        let mut config = Self::default();
+
+        for (table, values) in &document {
+            for (key, value) in values {
+                let name = match table.as_str() {
+                    "" => key.clone(),
+                    table => format!("{}.{}", table, key),
+                };
+                match (table.as_str(), key.as_str()) {
+                    ("", "year") => config.year = Some(integer(&name, value)?),
+                    ("", "days") => match integer(&name, value)? {
+                        days @ 1..=25 => config.days = Some(days),
+                        _ => return Err(format!("`{}` is not between 1 and 25", name)),
+                    },
+                    ("dirs", "inputs") => config.inputs_dir = Some(string(&name, value)?),
+                    ("dirs", "examples") => config.examples_dir = Some(string(&name, value)?),
+                    ("bench", "iterations") => {
+                        config.bench_iterations = Some(integer(&name, value)?)
+                    }
+                    ("timeouts", "default") => config.timeout = Some(seconds(&name, value)?),
+                    ("timeouts", day) => {
+                        match day.strip_prefix("day").and_then(|d| parse_day(d).ok()) {
+                            Some(day) => {
+                                config.day_timeouts.insert(day, seconds(&name, value)?);
+                            }
+                            None => return Err(format!("unknown setting `{}`", name)),
+                        }
+                    }
+                    ("download", "session_file") => config.session_file = Some(path(&name, value)?),
+                    _ => return Err(format!("unknown setting `{}`", name)),
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Loads `aoc.toml`. A missing file yields the default settings.
+    pub fn load() -> Result<Self, String> {
+        let path = config_path();
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// The time limit of a day, if any.
+    pub fn timeout(&self, day: u8) -> Option<Duration> {
+        self.day_timeouts.get(&day).copied().or(self.timeout)
+    }
+}
+
+/// The settings in `aoc.toml`, loaded once on first use. Binaries call this first to report
+/// an invalid file, see [`config`].
+pub fn load_config() -> Result<&'static Config, String> {
+    static CONFIG: OnceLock<Result<Config, String>> = OnceLock::new();
+    CONFIG
+        .get_or_init(Config::load)
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+/// The settings in `aoc.toml`, or the default settings if it is invalid.
+pub fn config() -> &'static Config {
+    static DEFAULT: OnceLock<Config> = OnceLock::new();
+    load_config().unwrap_or_else(|_| DEFAULT.get_or_init(Config::default))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            "year = 2021\ndays = 12\n\n[dirs]\ninputs = \"inputs/{year}\"\n\n[bench]\niterations = 50\n\n[timeouts]\ndefault = 10\nday11 = \"0.5\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2021));
+        assert_eq!(config.days, Some(12));
+        assert_eq!(config.inputs_dir, Some("inputs/{year}".to_string()));
+        assert_eq!(config.examples_dir, None);
+        assert_eq!(config.bench_iterations, Some(50));
+        assert_eq!(config.timeout(1), Some(Duration::from_secs(10)));
+        assert_eq!(config.timeout(11), Some(Duration::from_millis(500)));
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Config::parse("year = \"2022\"").is_err());
+        assert!(Config::parse("days = 26").is_err());
+        assert!(Config::parse("yaer = 2022").is_err());
+        assert!(Config::parse("[timeouts]\nday = 10").is_err());
+        assert!(Config::parse("[timeouts]\nday26 = 10").is_err());
+        assert!(Config::parse("[bench]\niterations = -1").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Readable diffs of grids and other multi-line answers, used by `assert_grid_eq!`.
+
+/// Differing cells listed below the grids, the rest is summed up.
+const MAX_LISTED: usize = 20;
+
+type Grid = Vec<Vec<char>>;
+
+fn cell(grid: &Grid, row: usize, col: usize) -> Option<char> {
+    grid.get(row).and_then(|line| line.get(col)).copied()
+}
+
+fn describe(cell: Option<char>) -> String {
+    match cell {
+        Some(c) => format!("{:?}", c),
+        None => "nothing".to_string(),
+    }
+}
+
+/// A line of a grid, padded to `width` characters.
+fn pad(line: &[char], width: usize) -> String {
+    let mut out: String = line.iter().collect();
+    out.extend(std::iter::repeat_n(' ', width - line.len()));
+    out
+}
+
+/// A line with `^` below every differing column.
+fn markers(cols: &[usize], width: usize) -> String {
+    (0..width)
+        .map(|col| match cols.contains(&col) {
+            true => '^',
+            false => ' ',
+        })
+        .collect()
+}
+
+/// Column numbers above the grids: the tens every ten columns, then the units.
+fn rulers(width: usize) -> [String; 2] {
+    let tens = (0..width)
+        .map(|col| match col % 10 {
+            0 if col > 0 => char::from(b'0' + (col / 10 % 10) as u8),
+            _ => ' ',
+        })
+        .collect();
+    let units = (0..width)
+        .map(|col| char::from(b'0' + (col % 10) as u8))
+        .collect();
+    [tens, units]
+}
+
+/// Compares two grids cell by cell. Returns `None` if they are equal, otherwise both grids side by
+/// side with the differing rows marked by `>`, the differing cells marked by `^` and a list of
+/// their row and column, counted from zero.
+pub fn grid_diff(actual: &str, expected: &str) -> Option<String> {
+    if actual == expected {
+        return None;
+    }
+
+    let expected: Grid = expected
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+    let actual: Grid = actual.lines().map(|line| line.chars().collect()).collect();
+    let grid_width = |grid: &Grid| grid.iter().map(Vec::len).max().unwrap_or(0);
+    let width = grid_width(&expected).max(grid_width(&actual));
+    let rows = expected.len().max(actual.len());
+    let row_digits = rows.saturating_sub(1).to_string().len();
+    // every column is at least as wide as its title.
+    let column = width.max("expected".len());
+
+    let side_by_side = |prefix: char, row: &str, left: &str, right: &str| {
+        format!(
+            "{}{:>w$} {:c$} | {}",
+            prefix,
+            row,
+            left,
+            right,
+            w = row_digits,
+            c = column
+        )
+        .trim_end()
+        .to_string()
+            + "\n"
+    };
+
+    let mut out = side_by_side(' ', "", "expected", "actual");
+    for ruler in rulers(width)
+        .iter()
+        .filter(|ruler| !ruler.trim().is_empty())
+    {
+        out += &side_by_side(' ', "", ruler, ruler);
+    }
+
+    let mut differences = Vec::new();
+    for row in 0..rows {
+        let cols: Vec<usize> = (0..width)
+            .filter(|&col| cell(&expected, row, col) != cell(&actual, row, col))
+            .collect();
+        let line = |grid: &Grid| pad(grid.get(row).map_or(&[][..], Vec::as_slice), width);
+
+        match cols.is_empty() {
+            true => out += &side_by_side(' ', &row.to_string(), &line(&expected), &line(&actual)),
+            false => {
+                out += &side_by_side('>', &row.to_string(), &line(&expected), &line(&actual));
+                let markers = markers(&cols, width);
+                out += &side_by_side(' ', "", &markers, &markers);
+            }
+        }
+        differences.extend(cols.into_iter().map(|col| (row, col)));
+    }
+
+    if differences.is_empty() {
+        out += "\nThe cells are equal, the grids differ in trailing whitespace or line endings.\n";
+        return Some(out);
+    }
+
+    out += &format!(
+        "\n{} differing {}:\n",
+        differences.len(),
+        match differences.len() {
+            1 => "cell",
+            _ => "cells",
+        }
+    );
+    for &(row, col) in differences.iter().take(MAX_LISTED) {
+        out += &format!(
+            "  row {}, column {}: expected {}, got {}\n",
+            row,
+            col,
+            describe(cell(&expected, row, col)),
+            describe(cell(&actual, row, col))
+        );
+    }
+    if differences.len() > MAX_LISTED {
+        out += &format!("  ... and {} more\n", differences.len() - MAX_LISTED);
+    }
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_diff() {
+        assert_eq!(grid_diff("#.\n.#", "#.\n.#"), None);
+        let lines = [
+            "   expected | actual",
+            "   0123     | 0123",
+            ">0 #...     | ##..",
+            "    ^       |  ^",
+            " 1 #.#.     | #.#.",
+            ">2 ....     | ..",
+            "     ^^     |   ^^",
+            "",
+            "3 differing cells:",
+            "  row 0, column 1: expected '.', got '#'",
+            "  row 2, column 2: expected '.', got nothing",
+            "  row 2, column 3: expected '.', got nothing",
+        ];
+        assert_eq!(
+            grid_diff("##..\n#.#.\n..", "#...\n#.#.\n...."),
+            Some(lines.join("\n") + "\n")
+        );
+    }
+
+    #[test]
+    fn test_grid_diff_line_endings() {
+        let diff = grid_diff("#.\n", "#.").unwrap();
+        assert!(diff.ends_with("the grids differ in trailing whitespace or line endings.\n"));
+    }
+
+    #[test]
+    fn test_rulers() {
+        assert_eq!(
+            rulers(12),
+            ["          1 ".to_string(), "012345678901".to_string()]
+        );
+    }
+}
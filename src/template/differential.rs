@@ -0,0 +1,261 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Differential tests that compare a solution with a slow reference implementation on
+//! generated inputs, set up with `differential_tests!`.
+use crate::template::runner::catch_panic;
+use crate::template::Answer;
+use std::env;
+
+/// Environment variable with the seed of the generated inputs, to try other inputs than the default ones.
+pub const SEED_ENV: &str = "AOC_SEED";
+
+/// A small, seedable pseudo-random number generator (SplitMix64) for generating inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `start..end`. Panics if the range is empty.
+    pub fn range(&mut self, start: usize, end: usize) -> usize {
+        assert!(start < end, "empty range {}..{}", start, end);
+        start + (self.next_u64() % (end - start) as u64) as usize
+    }
+
+    /// `true` with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.range(0, denominator) < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len())]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Seed of the first generated input, read from `AOC_SEED` by default.
+    pub seed: u64,
+    /// Number of generated inputs.
+    pub cases: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: env::var(SEED_ENV)
+                .ok()
+                .and_then(|seed| seed.parse().ok())
+                .unwrap_or(2022),
+            cases: 200,
+        }
+    }
+}
+
+/// What a part returned, or the message of its panic.
+type Outcome = Result<Result<Option<Answer>, String>, String>;
+
+fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Ok(Ok(Some(answer))) => answer.to_string(),
+        Ok(Ok(None)) => "no answer".to_string(),
+        Ok(Err(error)) => format!("error: {}", error),
+        Err(message) => format!("panicked: {}", message),
+    }
+}
+
+/// A generated input on which the solution and the reference disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// Index of the generated input, which is seeded with `seed + case`.
+    pub case: usize,
+    pub original_lines: usize,
+    /// The smallest input found by shrinking that still fails.
+    pub input: String,
+    pub solution: String,
+    pub reference: String,
+}
+
+/// Inputs with some of the lines of `input` removed, largest removals first.
+pub fn shrink_lines(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut candidates = Vec::new();
+
+    let mut size = lines.len() / 2;
+    while size > 0 {
+        for start in (0..lines.len()).step_by(size) {
+            let kept: Vec<&str> = lines[..start]
+                .iter()
+                .chain(lines[(start + size).min(lines.len())..].iter())
+                .copied()
+                .collect();
+            if !kept.is_empty() {
+                candidates.push(kept.join("\n") + "\n");
+            }
+        }
+        size /= 2;
+    }
+
+    candidates
+}
+
+/// Runs both implementations on `config.cases` generated inputs and shrinks the first input
+/// they disagree on. Inputs that make the reference panic are invalid and skipped.
+pub fn find_counterexample(
+    config: &Config,
+    generate: impl Fn(&mut Rng) -> String,
+    shrink: impl Fn(&str) -> Vec<String>,
+    solution: impl Fn(&str) -> Result<Option<Answer>, String>,
+    reference: impl Fn(&str) -> Result<Option<Answer>, String>,
+) -> Option<Counterexample> {
+    // the outcomes of a failing input, or `None` if it passes or is invalid.
+    let run = |input: &str| -> Option<(Outcome, Outcome)> {
+        let expected = catch_panic(|| reference(input));
+        if expected.is_err() {
+            return None;
+        }
+        let actual = catch_panic(|| solution(input));
+        match actual == expected {
+            true => None,
+            false => Some((actual, expected)),
+        }
+    };
+
+    for case in 0..config.cases {
+        let input = generate(&mut Rng::new(config.seed.wrapping_add(case as u64)));
+        let mut failure = match run(&input) {
+            Some(outcomes) => (input.clone(), outcomes),
+            None => continue,
+        };
+
+        // greedily take the first smaller input that still fails, until none does.
+        while let Some(smaller) = shrink(&failure.0)
+            .into_iter()
+            .find_map(|candidate| run(&candidate).map(|outcomes| (candidate, outcomes)))
+        {
+            failure = smaller;
+        }
+
+        let (shrunk, (actual, expected)) = failure;
+        return Some(Counterexample {
+            case,
+            original_lines: input.lines().count(),
+            input: shrunk,
+            solution: describe(&actual),
+            reference: describe(&expected),
+        });
+    }
+
+    None
+}
+
+/// Panics with the shrunk counterexample if the solution and the reference disagree on a
+/// generated input. Used by the tests generated with `differential_tests!`.
+pub fn check(
+    part: u8,
+    generate: impl Fn(&mut Rng) -> String,
+    solution: impl Fn(&str) -> Result<Option<Answer>, String>,
+    reference: impl Fn(&str) -> Result<Option<Answer>, String>,
+) {
+    let config = Config::default();
+    if let Some(failure) = find_counterexample(&config, generate, shrink_lines, solution, reference)
+    {
+        panic!(
+            "part {} differs from the reference on generated input {} of seed {}, shrunk from {} to {} lines:\n{}\nsolution:  {}\nreference: {}",
+            part,
+            failure.case,
+            config.seed,
+            failure.original_lines,
+            failure.input.lines().count(),
+            failure.input,
+            failure.solution,
+            failure.reference
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(rng: &mut Rng) -> String {
+        let count = rng.range(1, 20);
+        (0..count)
+            .map(|_| format!("{}\n", rng.range(0, 100)))
+            .collect()
+    }
+
+    fn sum(input: &str) -> Result<Option<Answer>, String> {
+        let sum: usize = input.lines().map(|l| l.parse::<usize>().unwrap()).sum();
+        Ok(Some(Answer::Number(sum as i128)))
+    }
+
+    /// Forgets numbers above 90.
+    fn buggy_sum(input: &str) -> Result<Option<Answer>, String> {
+        let sum: usize = input
+            .lines()
+            .map(|l| l.parse::<usize>().unwrap())
+            .filter(|n| *n <= 90)
+            .sum();
+        Ok(Some(Answer::Number(sum as i128)))
+    }
+
+    #[test]
+    fn test_rng() {
+        let sample = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.range(3, 6)).collect::<Vec<_>>()
+        };
+        assert!(sample(7).iter().all(|n| (3..6).contains(n)));
+        assert_eq!(sample(7), sample(7));
+        assert_ne!(sample(7), sample(8));
+    }
+
+    #[test]
+    fn test_shrink_lines() {
+        assert_eq!(
+            shrink_lines("a\nb\nc\nd\n"),
+            [
+                "c\nd\n",
+                "a\nb\n",
+                "b\nc\nd\n",
+                "a\nc\nd\n",
+                "a\nb\nd\n",
+                "a\nb\nc\n"
+            ]
+        );
+        assert!(shrink_lines("a\n").is_empty());
+    }
+
+    #[test]
+    fn test_find_counterexample() {
+        let config = Config {
+            seed: 1,
+            cases: 100,
+        };
+        assert_eq!(
+            find_counterexample(&config, numbers, shrink_lines, sum, sum),
+            None
+        );
+
+        let failure = find_counterexample(&config, numbers, shrink_lines, buggy_sum, sum).unwrap();
+        assert_eq!(failure.input.lines().count(), 1);
+        assert!(failure.input.trim().parse::<usize>().unwrap() > 90);
+        assert_eq!(
+            failure,
+            find_counterexample(&config, numbers, shrink_lines, buggy_sum, sum).unwrap()
+        );
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Named example inputs with expected answers, stored in `src/<year>/examples/NN.toml`
+//! and checked by the tests that `example_tests!` generates.
+//!
+//! ```toml
+//! [first]
+//! input = '''
+//! mjqjpqmgbljsphdztnvjfqwrcgsmlb
+//! '''
+//! part_one = 7
+//! part_two = 19
+//!
+//! [from_file]
+//! file = "06.txt"
+//! part_one = 7
+//! ```
+use crate::template::diff::grid_diff;
+use crate::template::runner::catch_panic;
+use crate::template::toml::{self, Table, Value};
+use crate::template::{Answer, Solution};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single example input of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            _ => self.part_two.as_ref(),
+        }
+    }
+}
+
+pub fn examples_path(year: u16, day: u8) -> PathBuf {
+    crate::data_dir(year, "examples").join(format!("{:02}.toml", day))
+}
+
+/// Loads the examples of a day, in the order of their names.
+pub fn load(year: u16, day: u8) -> Result<Vec<Example>, String> {
+    let path = examples_path(year, day);
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    let dir = path.parent().unwrap_or(Path::new("."));
+    parse(&contents, dir).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Parses examples. Every table is an example with either an `input` or a `file` relative to `dir`,
+/// and optionally the expected answers `part_one` and `part_two`.
+pub fn parse(contents: &str, dir: &Path) -> Result<Vec<Example>, String> {
+    let document = toml::parse(contents)?;
+    let mut examples = Vec::new();
+
+    for (name, table) in document {
+        if name.is_empty() {
+            continue;
+        }
+        let input = match (table.get("input"), table.get("file")) {
+            (Some(Value::String(input)), None) => input.clone(),
+            (None, Some(Value::String(file))) => {
+                let path = dir.join(file);
+                fs::read_to_string(&path)
+                    .map_err(|e| format!("[{}]: could not read {}: {}", name, path.display(), e))?
+            }
+            _ => return Err(format!("[{}]: expected either `input` or `file`", name)),
+        };
+        let answer =
+            |key: &str| expected_answer(&table, key).map_err(|e| format!("[{}]: {}", name, e));
+
+        examples.push(Example {
+            part_one: answer("part_one")?,
+            part_two: answer("part_two")?,
+            name,
+            input,
+        });
+    }
+
+    Ok(examples)
+}
+
+/// Reads the expected answer stored under `key`, e.g. `part_one = 7`.
+pub(crate) fn expected_answer(table: &Table, key: &str) -> Result<Option<Answer>, String> {
+    match table.get(key) {
+        Some(Value::Integer(n)) => Ok(Some(Answer::Number(*n))),
+        Some(Value::String(s)) => Ok(Some(Answer::Text(s.clone()))),
+        Some(Value::Boolean(_)) => Err(format!("`{}` is not an answer", key)),
+        None => Ok(None),
+    }
+}
+
+/// Runs a part on every example with an expected answer for it, and returns a line per
+/// example that didn't produce that answer.
+pub fn failures(solution: &dyn Solution, part: u8, examples: &[Example]) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    for example in examples {
+        let expected = match example.expected(part) {
+            Some(expected) => expected,
+            None => continue,
+        };
+        let output = catch_panic(|| match part {
+            1 => solution.part_one(&example.input),
+            _ => solution.part_two(&example.input),
+        });
+
+        let failure = match output {
+            Ok(Ok(Some(answer))) if answer.to_string() == expected.to_string() => continue,
+            Ok(Ok(Some(Answer::Text(answer)))) if expected.to_string().contains('\n') => {
+                let diff = grid_diff(&answer, &expected.to_string()).unwrap_or_default();
+                format!("got a different grid:\n{}", diff)
+            }
+            Ok(Ok(Some(answer))) => format!("expected {}, got {}", expected, answer),
+            Ok(Ok(None)) => format!("expected {}, got no answer", expected),
+            Ok(Err(error)) => format!("expected {}, got error: {}", expected, error),
+            Err(message) => format!("expected {}, panicked: {}", expected, message),
+        };
+        failures.push(format!("[{}] {}", example.name, failure));
+    }
+
+    failures
+}
+
+/// Checks a part against the examples of its day and panics with every failing example.
+/// Also panics if no example has an expected answer for the part, so it doesn't pass unchecked.
+/// Used by the tests generated with `example_tests!`.
+pub fn check(solution: &dyn Solution, part: u8) {
+    let (year, day) = (solution.year(), solution.day());
+    let examples = match load(year, day) {
+        Ok(examples) => examples,
+        Err(e) => panic!("{}", e),
+    };
+    let checked = examples
+        .iter()
+        .filter(|e| e.expected(part).is_some())
+        .count();
+    if checked == 0 {
+        panic!(
+            "no example has an expected answer for part {}, add `{}` to an example in {}",
+            part,
+            match part {
+                1 => "part_one = <answer>",
+                _ => "part_two = <answer>",
+            },
+            examples_path(year, day).display()
+        );
+    }
+    let failures = failures(solution, part, &examples);
+    if !failures.is_empty() {
+        panic!(
+            "{} of {} examples failed for part {}:\n{}",
+            failures.len(),
+            checked,
+            part,
+            failures.join("\n")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Length;
+
+    impl Solution for Length {
+        fn year(&self) -> u16 {
+            2022
+        }
+
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn part_one(&self, input: &str) -> Result<Option<Answer>, String> {
+            Ok(Some(Answer::Number(input.trim().len() as i128)))
+        }
+
+        fn part_two(&self, input: &str) -> Result<Option<Answer>, String> {
+            match input.trim() {
+                "" => Err("empty input".to_string()),
+                input => Ok(Some(Answer::Text(input.to_uppercase()))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let examples = parse(
+            "[a]\ninput = '''\nabc\n'''\npart_one = 3\npart_two = \"ABC\"\n\n[b]\ninput = \"\"\npart_two = \"\"\n",
+            Path::new("."),
+        )
+        .unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    name: "a".to_string(),
+                    input: "abc\n".to_string(),
+                    part_one: Some(Answer::Number(3)),
+                    part_two: Some(Answer::Text("ABC".to_string())),
+                },
+                Example {
+                    name: "b".to_string(),
+                    input: String::new(),
+                    part_one: None,
+                    part_two: Some(Answer::Text(String::new())),
+                },
+            ]
+        );
+        assert_eq!(
+            parse("[a]\npart_one = 1\n", Path::new(".")),
+            Err("[a]: expected either `input` or `file`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        let examples = parse(
+            "[a]\ninput = \"abc\"\npart_one = 3\npart_two = \"ABC\"\n\n[b]\ninput = \"\"\npart_one = 1\npart_two = \"\"\n",
+            Path::new("."),
+        )
+        .unwrap();
+
+        assert!(failures(&Length, 1, &examples[..1]).is_empty());
+        assert_eq!(failures(&Length, 1, &examples), ["[b] expected 1, got 0"]);
+        assert_eq!(
+            failures(&Length, 2, &examples),
+            ["[b] expected , got error: empty input"]
+        );
+    }
+}
@@ -0,0 +1,408 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Timings of past `cargo all` runs, stored in `src/<year>/history.csv`. Only timings of the
+//! same build profile and mode are compared.
+use crate::template::args::Options;
+use crate::template::runner::DayResult;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const HEADER: &str = "run,profile,mode,day,part,duration_ns\n";
+
+/// Number of previous runs that make up the baseline of a part.
+pub const BASELINE_RUNS: usize = 5;
+
+/// A part is flagged when it is this many times slower than its baseline.
+pub const REGRESSION_FACTOR: f64 = 1.5;
+
+pub fn history_path(year: u16) -> PathBuf {
+    crate::records_dir(year).join("history.csv")
+}
+
+/// Debug and release timings are not comparable, so every entry stores the build profile.
+pub fn profile() -> &'static str {
+    match cfg!(debug_assertions) {
+        true => "debug",
+        false => "release",
+    }
+}
+
+/// Medians of `--bench` and single timings are not comparable either, see [`profile`].
+pub fn mode(options: &Options) -> &'static str {
+    match options.bench {
+        Some(_) => "bench",
+        None => "single",
+    }
+}
+
+/// The timing of a part in a single run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Start of the run, in milliseconds since the unix epoch.
+    pub run: u128,
+    pub profile: String,
+    /// How the part was timed, see [`mode`].
+    pub mode: String,
+    pub day: u8,
+    /// The part, or `0` for the parse stage of a two-stage solution.
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+/// All recorded entries, oldest first.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct History(Vec<Entry>);
+
+impl History {
+    /// Loads `history.csv` from the [`crate::records_dir`]. A missing file yields an empty history.
+    pub fn load(year: u16) -> Result<Self, String> {
+        let path = history_path(year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Parses the entries of `history.csv`.
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            if index == 0 && line == HEADER.trim_end() || line.trim().is_empty() {
+                continue;
+            }
+
+            let error = || format!("line {}: invalid entry `{}`", index + 1, line);
+            let fields: Vec<&str> = line.split(',').collect();
+            if fields.len() != 6 {
+                return Err(error());
+            }
+            entries.push(Entry {
+                run: fields[0].parse().map_err(|_| error())?,
+                profile: fields[1].to_string(),
+                mode: fields[2].to_string(),
+                day: fields[3].parse().map_err(|_| error())?,
+                part: fields[4].parse().map_err(|_| error())?,
+                elapsed: Duration::from_nanos(fields[5].parse().map_err(|_| error())?),
+            });
+        }
+
+        Ok(Self(entries))
+    }
+
+    /// Timings of a part that are comparable with `entry`, oldest first.
+    fn timings(&self, entry: &Entry) -> Vec<Duration> {
+        self.0
+            .iter()
+            .filter(|e| {
+                e.profile == entry.profile
+                    && e.mode == entry.mode
+                    && e.day == entry.day
+                    && e.part == entry.part
+            })
+            .map(|e| e.elapsed)
+            .collect()
+    }
+
+    /// The most recent timing of a part for a profile, in any mode.
+    pub fn last(&self, profile: &str, day: u8, part: u8) -> Option<Duration> {
+        self.0
+            .iter()
+            .rev()
+            .find(|e| e.profile == profile && e.day == day && e.part == part)
+            .map(|e| e.elapsed)
+    }
+}
+
+/// The entries of a run for the parse stage and every solved part.
+pub fn entries(run: u128, mode: &str, results: &[DayResult]) -> Vec<Entry> {
+    let entry = |day, part, elapsed| Entry {
+        run,
+        profile: profile().to_string(),
+        mode: mode.to_string(),
+        day,
+        part,
+        elapsed,
+    };
+    let mut entries = Vec::new();
+
+    for result in results {
+        let parts = match &result.parts {
+            Some(parts) => parts,
+            None => continue,
+        };
+        if let Some(parse) = &result.parse {
+            entries.push(entry(result.day, 0, parse.elapsed));
+        }
+        for part in parts.iter().filter(|part| part.answer.is_some()) {
+            entries.push(entry(result.day, part.part, part.elapsed));
+        }
+    }
+
+    entries
+}
+
+pub fn now() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
+
+/// Appends entries to `history.csv` in the [`crate::records_dir`], creating it if necessary.
+pub fn append(year: u16, entries: &[Entry]) -> Result<(), String> {
+    let path = history_path(year);
+    let error = |e: std::io::Error| format!("could not write {}: {}", path.display(), e);
+
+    let mut contents = match path.exists() {
+        true => String::new(),
+        false => HEADER.to_string(),
+    };
+    for entry in entries {
+        contents += &format!(
+            "{},{},{},{},{},{}\n",
+            entry.run,
+            entry.profile,
+            entry.mode,
+            entry.day,
+            entry.part,
+            entry.elapsed.as_nanos()
+        );
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(error)
+}
+
+/// How a part of the current run compares with previous runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+    /// Timing of the most recent previous run.
+    pub last: Option<Duration>,
+    /// Median of up to [`BASELINE_RUNS`] previous runs.
+    pub baseline: Option<Duration>,
+    /// Slower than [`REGRESSION_FACTOR`] times the baseline.
+    pub regression: bool,
+}
+
+fn median(timings: &[Duration]) -> Option<Duration> {
+    let mut sorted = timings.to_vec();
+    sorted.sort();
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[n / 2]),
+        n => Some((sorted[n / 2 - 1] + sorted[n / 2]) / 2),
+    }
+}
+
+/// Compares the entries of the current run with the history before it.
+pub fn compare(history: &History, current: &[Entry]) -> Vec<Comparison> {
+    current
+        .iter()
+        .map(|entry| {
+            let timings = history.timings(entry);
+            let recent = &timings[timings.len().saturating_sub(BASELINE_RUNS)..];
+            let baseline = median(recent);
+
+            Comparison {
+                day: entry.day,
+                part: entry.part,
+                elapsed: entry.elapsed,
+                last: timings.last().copied(),
+                baseline,
+                regression: baseline.is_some_and(|baseline| {
+                    entry.elapsed.as_secs_f64() > baseline.as_secs_f64() * REGRESSION_FACTOR
+                }),
+            }
+        })
+        .collect()
+}
+
+fn change(elapsed: Duration, previous: Duration) -> String {
+    match previous.is_zero() {
+        true => "n/a".to_string(),
+        false => format!(
+            "{:+.1}%",
+            (elapsed.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0
+        ),
+    }
+}
+
+pub fn print_comparisons(comparisons: &[Comparison]) {
+    println!("{}📈 Compared to previous runs:{}", ANSI_BOLD, ANSI_RESET);
+
+    for comparison in comparisons {
+        let line = match (comparison.last, comparison.baseline) {
+            (Some(last), Some(baseline)) => format!(
+                "{:.2?} {}(last run: {:.2?}, {}, baseline: {:.2?}){}",
+                comparison.elapsed,
+                ANSI_ITALIC,
+                last,
+                change(comparison.elapsed, last),
+                baseline,
+                ANSI_RESET
+            ),
+            _ => format!(
+                "{:.2?} {}(no previous runs){}",
+                comparison.elapsed, ANSI_ITALIC, ANSI_RESET
+            ),
+        };
+        let flag = match comparison.regression {
+            true => " ⚠️ slower than baseline",
+            false => "",
+        };
+        let stage = match comparison.part {
+            0 => "parse".to_string(),
+            part => format!("part {}", part),
+        };
+        println!("Day {:02}, {}: {}{}", comparison.day, stage, line, flag);
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.regression).count();
+    if regressions > 0 {
+        println!(
+            "{}{} part(s) got more than {}x slower than their baseline.{}",
+            ANSI_BOLD, regressions, REGRESSION_FACTOR, ANSI_RESET
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::runner::{ParseResult, PartResult};
+    use crate::template::Answer;
+
+    fn entry(run: u128, day: u8, part: u8, micros: u64) -> Entry {
+        Entry {
+            run,
+            profile: "release".to_string(),
+            mode: "single".to_string(),
+            day,
+            part,
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let history = History::parse(
+            "run,profile,mode,day,part,duration_ns\n1,release,single,7,1,120000\n2,release,bench,7,0,5000\n",
+        )
+        .unwrap();
+        assert_eq!(
+            history,
+            History(vec![
+                entry(1, 7, 1, 120),
+                Entry {
+                    mode: "bench".to_string(),
+                    ..entry(2, 7, 0, 5)
+                }
+            ])
+        );
+        assert_eq!(
+            History::parse("1,release,7\n"),
+            Err("line 1: invalid entry `1,release,7`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_entries() {
+        let solved = |day, part| PartResult {
+            answer: Some(Answer::Number(1)),
+            elapsed: Duration::from_micros(10),
+            ..PartResult::unsolved(day, part)
+        };
+        let results = [
+            DayResult {
+                day: 7,
+                parse: Some(ParseResult {
+                    day: 7,
+                    elapsed: Duration::from_micros(5),
+                    stats: None,
+                    alloc: None,
+                    peak_rss: None,
+                }),
+                parts: Some(vec![solved(7, 1), PartResult::unsolved(7, 2)]),
+                peak_rss: None,
+                stderr: String::new(),
+            },
+            DayResult {
+                parts: Some(vec![solved(8, 1), solved(8, 2)]),
+                ..DayResult::unsolved(8)
+            },
+            DayResult::unsolved(9),
+        ];
+
+        let entries = entries(1, "single", &results);
+        assert_eq!(
+            entries
+                .iter()
+                .map(|e| (e.day, e.part, e.elapsed.as_micros()))
+                .collect::<Vec<_>>(),
+            vec![(7, 0, 5), (7, 1, 10), (8, 1, 10), (8, 2, 10)]
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = History(vec![
+            entry(1, 7, 1, 100),
+            entry(2, 7, 1, 120),
+            entry(3, 7, 1, 110),
+            entry(3, 7, 2, 50),
+            Entry {
+                mode: "bench".to_string(),
+                ..entry(3, 7, 2, 10)
+            },
+        ]);
+        let comparisons = compare(
+            &history,
+            &[entry(4, 7, 1, 1200), entry(4, 7, 2, 60), entry(4, 8, 1, 10)],
+        );
+
+        assert_eq!(
+            comparisons,
+            vec![
+                Comparison {
+                    day: 7,
+                    part: 1,
+                    elapsed: Duration::from_micros(1200),
+                    last: Some(Duration::from_micros(110)),
+                    baseline: Some(Duration::from_micros(110)),
+                    regression: true,
+                },
+                Comparison {
+                    day: 7,
+                    part: 2,
+                    elapsed: Duration::from_micros(60),
+                    last: Some(Duration::from_micros(50)),
+                    baseline: Some(Duration::from_micros(50)),
+                    regression: false,
+                },
+                Comparison {
+                    day: 8,
+                    part: 1,
+                    elapsed: Duration::from_micros(10),
+                    last: None,
+                    baseline: None,
+                    regression: false,
+                },
+            ]
+        );
+    }
+}
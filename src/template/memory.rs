@@ -0,0 +1,48 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Peak resident memory (RSS) of the process, read from `/proc/self/status` on Linux.
+//! Other platforms report no memory usage. The peak covers the whole process: memory that the
+//! allocator kept from earlier stages counts towards it, so it is an approximation.
+use std::fs;
+
+/// Resets the peak RSS of the process to its current RSS, so that [`peak_rss`] only covers
+/// what runs afterwards. Does nothing if the kernel doesn't support it.
+pub fn reset_peak() {
+    // "5" resets the peak RSS, see `man 5 proc` for `/proc/pid/clear_refs`.
+    let _ = fs::write("/proc/self/clear_refs", "5");
+}
+
+/// Peak RSS of the process in bytes, from the `VmHWM` line of `/proc/self/status`.
+pub fn peak_rss() -> Option<usize> {
+    parse_status(&fs::read_to_string("/proc/self/status").ok()?)
+}
+
+fn parse_status(status: &str) -> Option<usize> {
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes: usize = line
+        .trim_start_matches("VmHWM:")
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(
+            parse_status(
+                "Name:\tday08\nVmPeak:\t   12000 kB\nVmHWM:\t    3072 kB\nVmRSS:\t    2048 kB\n"
+            ),
+            Some(3 * 1024 * 1024)
+        );
+        assert_eq!(parse_status("Name:\tday08\n"), None);
+    }
+}
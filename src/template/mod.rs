@@ -0,0 +1,24 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+pub mod alloc;
+pub mod answers;
+pub mod args;
+pub mod batch;
+pub mod bench;
+pub mod config;
+pub mod diff;
+pub mod differential;
+pub mod examples;
+pub mod history;
+pub mod memory;
+pub mod readme;
+pub mod report;
+pub mod runner;
+mod solution;
+pub mod summary;
+pub mod toml;
+pub mod worker;
+
+pub use solution::{Answer, PartOutput, Solution, Stages};
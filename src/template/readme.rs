@@ -0,0 +1,224 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Writes the results of `cargo all -- --readme` to a marked section of `README.md`.
+use crate::template::alloc::format_bytes;
+use crate::template::answers::Verdict;
+use crate::template::runner::{DayResult, PartResult};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const SECTION_START: &str = "<!--- benchmarking table --->";
+pub const SECTION_END: &str = "<!--- benchmarking table end --->";
+
+pub fn readme_path() -> PathBuf {
+    crate::crate_dir().join("README.md")
+}
+
+/// Parts left out with `--part` are shown like unsolved ones.
+pub(crate) fn status(result: Option<&PartResult>) -> &'static str {
+    let result = match result {
+        Some(result) => result,
+        None => return "-",
+    };
+    match (&result.answer, &result.verdict) {
+        (Some(_), Some(Verdict::Correct)) => "✅",
+        (Some(_), Some(Verdict::Wrong { .. })) => "❌",
+        (Some(_), _) => "❔",
+        (None, _) if result.timed_out => "⏱️",
+        (None, _) if result.panicked => "💥",
+        (None, _) if result.error.is_some() => "⚠️",
+        (None, _) => "-",
+    }
+}
+
+pub(crate) fn elapsed(result: Option<&PartResult>) -> Option<Duration> {
+    result
+        .filter(|result| result.answer.is_some())
+        .map(|result| result.elapsed)
+}
+
+pub(crate) fn format_time(time: Option<Duration>) -> String {
+    match time {
+        Some(time) => format!("`{:.2?}`", time),
+        None => "-".to_string(),
+    }
+}
+
+fn format_memory(bytes: Option<usize>) -> String {
+    match bytes {
+        Some(bytes) => format!("`{}`", format_bytes(bytes)),
+        None => "-".to_string(),
+    }
+}
+
+/// Renders a markdown table with a row for every day of `year` that ran, followed by a total row.
+/// The parse column is only filled for two-stage solutions. The total row shows the highest
+/// peak memory of all days.
+pub fn table(year: u16, results: &[DayResult]) -> String {
+    let mut out = String::from(
+        "| Day | Part 1 | Part 2 | Parse | Time part 1 | Time part 2 | Total | Peak memory |\n| :---: | :---: | :---: | ---: | ---: | ---: | ---: | ---: |\n",
+    );
+    let mut totals = [Duration::ZERO; 3];
+    let mut peak_rss = None;
+
+    for result in results {
+        if result.parts.is_none() {
+            continue;
+        }
+        let parts = [result.part(1), result.part(2)];
+
+        let times = [
+            result.parse.as_ref().map(|parse| parse.elapsed),
+            elapsed(parts[0]),
+            elapsed(parts[1]),
+        ];
+        for (total, time) in totals.iter_mut().zip(times) {
+            *total += time.unwrap_or_default();
+        }
+        peak_rss = peak_rss.max(result.peak_rss);
+
+        out += &format!(
+            "| [Day {}](./src/{}/{:02}.rs) | {} | {} | {} | {} | {} | {} | {} |\n",
+            result.day,
+            year,
+            result.day,
+            status(parts[0]),
+            status(parts[1]),
+            format_time(times[0]),
+            format_time(times[1]),
+            format_time(times[2]),
+            format_time(Some(times.iter().flatten().sum())),
+            format_memory(result.peak_rss),
+        );
+    }
+
+    out += &format!(
+        "| **Total** | | | {} | {} | {} | **{}** | {} |\n",
+        format_time(Some(totals[0])),
+        format_time(Some(totals[1])),
+        format_time(Some(totals[2])),
+        format_time(Some(totals.iter().sum())),
+        format_memory(peak_rss),
+    );
+    out
+}
+
+/// Replaces the contents between the section markers of `readme` with `table`.
+pub fn update_section(readme: &str, table: &str) -> Result<String, String> {
+    let missing = |marker: &str| format!("could not find the marker `{}`", marker);
+    let start = readme
+        .find(SECTION_START)
+        .ok_or_else(|| missing(SECTION_START))?;
+    let content_start = start + SECTION_START.len();
+    let end = readme[content_start..]
+        .find(SECTION_END)
+        .map(|end| content_start + end)
+        .ok_or_else(|| missing(SECTION_END))?;
+
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &readme[..content_start],
+        table,
+        &readme[end..]
+    ))
+}
+
+/// Writes the table for the `results` of `year` to `README.md`.
+pub fn update_readme(year: u16, results: &[DayResult]) -> Result<(), String> {
+    let path = readme_path();
+    let readme = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    let updated = update_section(&readme, &table(year, results))
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    fs::write(&path, updated).map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::runner::ParseResult;
+    use crate::template::Answer;
+
+    fn part(day: u8, part: u8, answer: Option<i128>, micros: u64, verdict: Verdict) -> PartResult {
+        PartResult {
+            answer: answer.map(Answer::Number),
+            elapsed: Duration::from_micros(micros),
+            verdict: answer.map(|_| verdict),
+            ..PartResult::unsolved(day, part)
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let results = [
+            DayResult {
+                day: 1,
+                parse: Some(ParseResult {
+                    day: 1,
+                    elapsed: Duration::from_micros(20),
+                    stats: None,
+                    alloc: None,
+                    peak_rss: None,
+                }),
+                parts: Some(vec![
+                    part(1, 1, Some(24000), 40, Verdict::Correct),
+                    part(1, 2, Some(45000), 60, Verdict::Unknown),
+                ]),
+                peak_rss: Some(2560 * 1024),
+                stderr: String::new(),
+            },
+            DayResult {
+                day: 2,
+                parse: None,
+                parts: None,
+                peak_rss: None,
+                stderr: String::new(),
+            },
+            DayResult {
+                day: 3,
+                parse: None,
+                parts: Some(vec![part(
+                    3,
+                    1,
+                    Some(1),
+                    100,
+                    Verdict::Wrong {
+                        expected: Answer::Number(2),
+                    },
+                )]),
+                peak_rss: None,
+                stderr: String::new(),
+            },
+        ];
+
+        assert_eq!(
+            table(2022, &results),
+            "| Day | Part 1 | Part 2 | Parse | Time part 1 | Time part 2 | Total | Peak memory |\n\
+             | :---: | :---: | :---: | ---: | ---: | ---: | ---: | ---: |\n\
+             | [Day 1](./src/2022/01.rs) | ✅ | ❔ | `20.00µs` | `40.00µs` | `60.00µs` | `120.00µs` | `2.50 MiB` |\n\
+             | [Day 3](./src/2022/03.rs) | ❌ | - | - | `100.00µs` | - | `100.00µs` | - |\n\
+             | **Total** | | | `20.00µs` | `140.00µs` | `60.00µs` | **`220.00µs`** | `2.50 MiB` |\n"
+        );
+    }
+
+    #[test]
+    fn test_update_section() {
+        let readme = format!(
+            "# AoC\n\n{}\nold\n{}\n\n## Setup\n",
+            SECTION_START, SECTION_END
+        );
+        let updated = update_section(&readme, "| table |\n").unwrap();
+        assert_eq!(
+            updated,
+            format!(
+                "# AoC\n\n{}\n\n| table |\n\n{}\n\n## Setup\n",
+                SECTION_START, SECTION_END
+            )
+        );
+        assert_eq!(update_section(&updated, "| table |\n").unwrap(), updated);
+        assert!(update_section("# AoC\n", "").is_err());
+    }
+}
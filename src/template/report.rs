@@ -0,0 +1,249 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Report files of a `cargo all` run, written with `--report <format> --out <path>`.
+use crate::template::answers::Verdict;
+use crate::template::readme;
+use crate::template::runner::{DayResult, PartResult};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Csv,
+    Markdown,
+    Junit,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ReportFormat::Csv),
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            "junit" => Ok(ReportFormat::Junit),
+            _ => Err(format!(
+                "unknown report format \"{}\", expected csv, markdown or junit",
+                s
+            )),
+        }
+    }
+}
+
+fn parts(results: &[DayResult]) -> impl Iterator<Item = &PartResult> {
+    results
+        .iter()
+        .filter_map(|result| result.parts.as_ref())
+        .flatten()
+}
+
+pub fn render(format: ReportFormat, year: u16, results: &[DayResult]) -> String {
+    match format {
+        ReportFormat::Csv => csv(results),
+        ReportFormat::Markdown => readme::table(year, results),
+        ReportFormat::Junit => junit(year, results),
+    }
+}
+
+pub fn write(
+    format: ReportFormat,
+    path: &Path,
+    year: u16,
+    results: &[DayResult],
+) -> Result<(), String> {
+    fs::write(path, render(format, year, results))
+        .map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+fn csv_field(s: &str) -> String {
+    match s.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.to_string(),
+    }
+}
+
+/// One row per part that ran.
+pub fn csv(results: &[DayResult]) -> String {
+    let mut out = String::from(
+        "day,part,solved,answer,verdict,expected,error,panicked,timed_out,duration_ns\n",
+    );
+
+    for part in parts(results) {
+        let (verdict, expected) = match &part.verdict {
+            Some(Verdict::Correct) => ("correct", String::new()),
+            Some(Verdict::Wrong { expected }) => ("wrong", expected.to_string()),
+            Some(Verdict::Unknown) => ("unknown", String::new()),
+            None => ("", String::new()),
+        };
+        let answer = part
+            .answer
+            .as_ref()
+            .map(|a| a.to_string())
+            .unwrap_or_default();
+
+        out += &format!(
+            "{},{},{},{},{},{},{},{},{},{}\n",
+            part.day,
+            part.part,
+            part.answer.is_some(),
+            csv_field(&answer),
+            verdict,
+            csv_field(&expected),
+            csv_field(part.error.as_deref().unwrap_or_default()),
+            part.panicked,
+            part.timed_out,
+            part.elapsed.as_nanos()
+        );
+    }
+
+    out
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// A test suite with a test case per part. Wrong answers, errors, panics and timeouts are
+/// failures, unsolved parts are skipped.
+pub fn junit(year: u16, results: &[DayResult]) -> String {
+    let mut cases = String::new();
+    let (mut tests, mut failures, mut skipped) = (0, 0, 0);
+    let mut time = 0.0;
+
+    for part in parts(results) {
+        tests += 1;
+        time += part.elapsed.as_secs_f64();
+        cases += &format!(
+            "    <testcase name=\"day {:02} part {}\" classname=\"aoc{}.day{:02}\" time=\"{:.6}\"",
+            part.day,
+            part.part,
+            year,
+            part.day,
+            part.elapsed.as_secs_f64()
+        );
+
+        match part.failure() {
+            Some(failure) => {
+                failures += 1;
+                cases += &format!(
+                    ">\n      <failure message=\"{}\"/>\n    </testcase>\n",
+                    xml_escape(&failure.to_string())
+                );
+            }
+            None if part.answer.is_none() => {
+                skipped += 1;
+                cases += ">\n      <skipped message=\"not solved\"/>\n    </testcase>\n";
+            }
+            None => cases += "/>\n",
+        }
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n  <testsuite name=\"Advent of Code {}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">\n{}  </testsuite>\n</testsuites>\n",
+        year, tests, failures, skipped, time, cases
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::Answer;
+    use std::time::Duration;
+
+    fn results() -> Vec<DayResult> {
+        vec![
+            DayResult {
+                day: 1,
+                parse: None,
+                parts: Some(vec![
+                    PartResult {
+                        answer: Some(Answer::Number(24000)),
+                        elapsed: Duration::from_micros(40),
+                        verdict: Some(Verdict::Correct),
+                        ..PartResult::unsolved(1, 1)
+                    },
+                    PartResult {
+                        answer: Some(Answer::Text("a,\"b\"".to_string())),
+                        elapsed: Duration::from_micros(60),
+                        verdict: Some(Verdict::Wrong {
+                            expected: Answer::Text("<c>".to_string()),
+                        }),
+                        ..PartResult::unsolved(1, 2)
+                    },
+                ]),
+                peak_rss: None,
+                stderr: String::new(),
+            },
+            DayResult {
+                day: 2,
+                parse: None,
+                parts: None,
+                peak_rss: None,
+                stderr: String::new(),
+            },
+            DayResult {
+                day: 3,
+                parse: None,
+                parts: Some(vec![
+                    PartResult {
+                        elapsed: Duration::from_secs(1),
+                        timed_out: true,
+                        ..PartResult::unsolved(3, 1)
+                    },
+                    PartResult::unsolved(3, 2),
+                ]),
+                peak_rss: None,
+                stderr: String::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            csv(&results()),
+            "day,part,solved,answer,verdict,expected,error,panicked,timed_out,duration_ns\n\
+             1,1,true,24000,correct,,,false,false,40000\n\
+             1,2,true,\"a,\"\"b\"\"\",wrong,<c>,,false,false,60000\n\
+             3,1,false,,,,,false,true,1000000000\n\
+             3,2,false,,,,,false,false,0\n"
+        );
+    }
+
+    #[test]
+    fn test_junit() {
+        assert_eq!(
+            junit(2022, &results()),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites>\n  \
+             <testsuite name=\"Advent of Code 2022\" tests=\"4\" failures=\"2\" skipped=\"1\" time=\"1.000100\">\n    \
+             <testcase name=\"day 01 part 1\" classname=\"aoc2022.day01\" time=\"0.000040\"/>\n    \
+             <testcase name=\"day 01 part 2\" classname=\"aoc2022.day01\" time=\"0.000060\">\n      \
+             <failure message=\"wrong answer a,&quot;b&quot;, expected &lt;c&gt;\"/>\n    \
+             </testcase>\n    \
+             <testcase name=\"day 03 part 1\" classname=\"aoc2022.day03\" time=\"1.000000\">\n      \
+             <failure message=\"timed out after 1.00s\"/>\n    \
+             </testcase>\n    \
+             <testcase name=\"day 03 part 2\" classname=\"aoc2022.day03\" time=\"0.000000\">\n      \
+             <skipped message=\"not solved\"/>\n    \
+             </testcase>\n  \
+             </testsuite>\n\
+             </testsuites>\n"
+        );
+    }
+}
@@ -0,0 +1,1037 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::template::alloc::{self, AllocStats};
+use crate::template::answers::{answers_path, Answers, Verdict};
+use crate::template::args::{Format, Input, Options};
+use crate::template::bench::{self, Stats};
+use crate::template::memory;
+use crate::template::worker;
+use crate::template::{Answer, PartOutput, Solution, Stages};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The outcome of running a single part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// Duration of the single run, or the median when benchmarking.
+    pub elapsed: Duration,
+    pub stats: Option<Stats>,
+    /// Heap usage of a single run, with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+    /// Peak resident memory of the process while the part ran, in bytes. Only set on Linux.
+    /// Approximate, it includes what the process held before the part started.
+    pub peak_rss: Option<usize>,
+    /// Comparison with `src/answers.toml`. Not set for unsolved parts.
+    pub verdict: Option<Verdict>,
+    /// The error returned by a `Result`-returning part, or the message of a panic.
+    pub error: Option<String>,
+    /// The part panicked, its message and location are stored in `error`.
+    pub panicked: bool,
+    /// The part did not finish within the day's time limit, which is stored in `elapsed`.
+    pub timed_out: bool,
+}
+
+impl PartResult {
+    /// A part that was not run, e.g. because the day has no input.
+    pub fn unsolved(day: u8, part: u8) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            stats: None,
+            alloc: None,
+            peak_rss: None,
+            verdict: None,
+            error: None,
+            panicked: false,
+            timed_out: false,
+        }
+    }
+
+    /// A part that panicked with `message`.
+    pub fn panicked(day: u8, part: u8, message: String) -> Self {
+        Self {
+            error: Some(message),
+            panicked: true,
+            ..Self::unsolved(day, part)
+        }
+    }
+
+    /// Why the part failed, if it did. Unsolved parts are not failures.
+    pub fn failure(&self) -> Option<Failure> {
+        if self.timed_out {
+            return Some(Failure::Timeout(self.elapsed));
+        }
+        match (&self.error, &self.answer, &self.verdict) {
+            (Some(message), _, _) if self.panicked => Some(Failure::Panic(message.clone())),
+            (Some(error), _, _) => Some(Failure::Error(error.clone())),
+            (None, Some(answer), Some(Verdict::Wrong { expected })) => Some(Failure::Wrong {
+                answer: answer.clone(),
+                expected: expected.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// The ways a part can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The part panicked, with the panic message and its location.
+    Panic(String),
+    /// The part returned an `Err`.
+    Error(String),
+    /// The answer differs from the one recorded in `src/<year>/answers.toml`.
+    Wrong { answer: Answer, expected: Answer },
+    /// The part did not finish within this time limit.
+    Timeout(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+            Failure::Error(error) => write!(f, "error: {}", error),
+            Failure::Wrong { answer, expected } => {
+                write!(f, "wrong answer {}, expected {}", answer, expected)
+            }
+            Failure::Timeout(limit) => write!(f, "timed out after {:.2?}", limit),
+        }
+    }
+}
+
+thread_local! {
+    static CAPTURE_PANICS: Cell<bool> = const { Cell::new(false) };
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Calls `func` and returns the message and location of its panic, if it panics.
+/// The panic is not printed to stderr, so that it can be reported with its day and part.
+pub(crate) fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURE_PANICS.with(Cell::get) {
+                return default_hook(info);
+            }
+            let payload = info.payload();
+            let message = match (
+                payload.downcast_ref::<&str>(),
+                payload.downcast_ref::<String>(),
+            ) {
+                (Some(message), _) => message.to_string(),
+                (_, Some(message)) => message.clone(),
+                _ => "Box<dyn Any>".to_string(),
+            };
+            let message = match info.location() {
+                Some(location) => format!("{} at {}", message, location),
+                None => message,
+            };
+            PANIC_MESSAGE.with(|panic| *panic.borrow_mut() = Some(message));
+        }));
+    });
+
+    let capturing = CAPTURE_PANICS.with(|capture| capture.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CAPTURE_PANICS.with(|capture| capture.set(capturing));
+
+    result.map_err(|_| {
+        PANIC_MESSAGE
+            .with(|panic| panic.borrow_mut().take())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// The outcome of the parsing stage of a two-stage solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseResult {
+    pub day: u8,
+    /// Duration of the single run, or the median when benchmarking.
+    pub elapsed: Duration,
+    pub stats: Option<Stats>,
+    pub alloc: Option<AllocStats>,
+    /// Peak resident memory of the process while parsing, in bytes. Only set on Linux.
+    pub peak_rss: Option<usize>,
+}
+
+/// Times `func`, repeatedly if `options.bench` is set. Returns its last output and the peak
+/// memory of the process while it ran.
+fn measure<T>(
+    mut func: impl FnMut() -> T,
+    options: &Options,
+) -> (
+    T,
+    Duration,
+    Option<Stats>,
+    Option<AllocStats>,
+    Option<usize>,
+) {
+    // allocations are counted during the first run, which is untimed when benchmarking.
+    let mut alloc = None;
+    let mut measured = false;
+    let mut run = || match std::mem::replace(&mut measured, true) {
+        false => {
+            let (output, stats) = alloc::measure(&mut func);
+            alloc = stats;
+            output
+        }
+        true => func(),
+    };
+
+    memory::reset_peak();
+    let (output, elapsed, stats) = match options.bench {
+        Some(iterations) => {
+            let (output, stats) = bench::bench(&mut run, iterations);
+            (output, stats.median, Some(stats))
+        }
+        None => {
+            let timer = Instant::now();
+            let output = run();
+            (output, timer.elapsed(), None)
+        }
+    };
+
+    (output, elapsed, stats, alloc, memory::peak_rss())
+}
+
+/// Runs and times the parsing stage of a day.
+pub fn run_parse(day: u8, func: impl FnMut(), options: &Options) -> ParseResult {
+    let ((), elapsed, stats, alloc, peak_rss) = measure(func, options);
+    ParseResult {
+        day,
+        elapsed,
+        stats,
+        alloc,
+        peak_rss,
+    }
+}
+
+/// Runs and times a single part, repeatedly if `options.bench` is set.
+pub fn run_part<T: PartOutput>(
+    day: u8,
+    part: u8,
+    func: impl FnMut() -> T,
+    options: &Options,
+) -> PartResult {
+    let (output, elapsed, stats, alloc, peak_rss) = measure(func, options);
+
+    let (answer, error) = match output.into_answer() {
+        Ok(answer) => (answer, None),
+        Err(e) => (None, Some(e)),
+    };
+
+    PartResult {
+        day,
+        part,
+        answer,
+        elapsed,
+        stats,
+        alloc,
+        peak_rss,
+        verdict: None,
+        error,
+        panicked: false,
+        timed_out: false,
+    }
+}
+
+fn load_answers(year: u16) -> Answers {
+    match Answers::load(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to load recorded answers: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn save_answers(year: u16, answers: &Answers) {
+    if let Err(e) = answers.save(year) {
+        eprintln!("Failed to record answers: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Compares a result with its recorded answer. With `record`, stores the answer afterwards.
+pub(crate) fn check_part(result: &mut PartResult, answers: &mut Answers, record: bool) {
+    if let Some(answer) = &result.answer {
+        result.verdict = Some(answers.check(result.day, result.part, answer));
+        if record {
+            answers.record(result.day, result.part, answer.clone());
+        }
+    }
+}
+
+/// A finished stage of a day.
+pub(crate) enum StageResult {
+    Parse(ParseResult),
+    Part(PartResult),
+}
+
+/// Times the stages handed over by [`Solution::run`] and passes each result to `on_result`.
+/// A panicking stage is reported as such; after a panic in the parse stage, the parts don't run.
+pub(crate) struct Timer<'a, F: FnMut(StageResult)> {
+    day: u8,
+    options: &'a Options,
+    on_result: F,
+    parse_panic: Option<String>,
+}
+
+impl<'a, F: FnMut(StageResult)> Timer<'a, F> {
+    pub(crate) fn new(day: u8, options: &'a Options, on_result: F) -> Self {
+        Self {
+            day,
+            options,
+            on_result,
+            parse_panic: None,
+        }
+    }
+}
+
+impl<F: FnMut(StageResult)> Stages for Timer<'_, F> {
+    fn parse(&mut self, func: &mut dyn FnMut()) {
+        match catch_panic(|| run_parse(self.day, func, self.options)) {
+            Ok(result) => (self.on_result)(StageResult::Parse(result)),
+            Err(message) => self.parse_panic = Some(format!("{} (while parsing)", message)),
+        }
+    }
+
+    fn part(&mut self, part: u8, func: &mut dyn FnMut() -> Result<Option<Answer>, String>) {
+        if !self.options.parts().contains(&part) {
+            return;
+        }
+        let result = match &self.parse_panic {
+            Some(message) => PartResult::panicked(self.day, part, message.clone()),
+            None => catch_panic(|| run_part(self.day, part, func, self.options))
+                .unwrap_or_else(|message| PartResult::panicked(self.day, part, message)),
+        };
+        (self.on_result)(StageResult::Part(result));
+    }
+}
+
+/// Runs a day in a solution binary and prints every stage as soon as it finishes.
+/// Answers are checked against `src/<year>/answers.toml` when running on the puzzle input.
+/// Exits with a non-zero status if a part failed, see [`PartResult::failure`].
+/// Honours the `--format`, `--bench`, `--record` and input arguments of the solution binary.
+pub fn solve_day(solution: &dyn Solution, input: &str, options: &Options) {
+    let (year, day) = (solution.year(), solution.day());
+    let check = options.input == Input::Puzzle;
+    let mut answers = match check {
+        true => load_answers(year),
+        false => Answers::default(),
+    };
+    let mut failed = false;
+    let mut peak_rss = None;
+
+    let on_result = |result| match result {
+        StageResult::Parse(result) => {
+            peak_rss = peak_rss.max(result.peak_rss);
+            print_parse(&result, options.format);
+        }
+        StageResult::Part(mut result) => {
+            if check {
+                check_part(&mut result, &mut answers, options.record);
+            }
+            peak_rss = peak_rss.max(result.peak_rss);
+            print_part(&result, options.format);
+            failed |= result.failure().is_some();
+        }
+    };
+    solution.run(input, &mut Timer::new(day, options, on_result));
+    if let (Some(peak_rss), Format::Text) = (peak_rss, options.format) {
+        print_memory(peak_rss);
+    }
+
+    if check && options.record {
+        save_answers(year, &answers);
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn alloc_label(alloc: &Option<AllocStats>) -> String {
+    match alloc {
+        Some(alloc) => format!(
+            ", allocs: {}, allocated: {}, peak: {}",
+            alloc.allocations,
+            alloc::format_bytes(alloc.bytes),
+            alloc::format_bytes(alloc.peak_bytes)
+        ),
+        None => String::new(),
+    }
+}
+
+fn verdict_label(verdict: &Option<Verdict>) -> String {
+    match verdict {
+        Some(Verdict::Correct) => "✅ ".to_string(),
+        Some(Verdict::Wrong { expected }) => format!("❌ (expected: {}) ", expected),
+        Some(Verdict::Unknown) => "❔ ".to_string(),
+        None => String::new(),
+    }
+}
+
+/// The timings of a stage, e.g. `(elapsed: 1.20ms)`, in italics.
+fn timing_label(elapsed: Duration, stats: &Option<Stats>, alloc: &Option<AllocStats>) -> String {
+    let timing = match stats {
+        None => format!("elapsed: {:.2?}", elapsed),
+        Some(stats) => format!(
+            "median: {:.2?}, min: {:.2?}, mean: {:.2?} ± {:.2?}, {} runs, {} mild / {} severe outliers",
+            stats.median,
+            stats.min,
+            stats.mean,
+            stats.stddev,
+            stats.samples,
+            stats.mild_outliers,
+            stats.severe_outliers
+        ),
+    };
+    format!(
+        "{}({}{}){}",
+        ANSI_ITALIC,
+        timing,
+        alloc_label(alloc),
+        ANSI_RESET
+    )
+}
+
+pub fn print_parse(result: &ParseResult, format: Format) {
+    match format {
+        Format::Text => {
+            println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+            println!(
+                "{}",
+                timing_label(result.elapsed, &result.stats, &result.alloc)
+            );
+        }
+        Format::Json => println!("{}", json_parse_record(result)),
+    }
+}
+
+/// Prints the peak memory of a day. JSON records have the peak memory of every stage instead.
+/// The peak covers the whole process, so it is only a rough figure, see [`memory`].
+pub fn print_memory(peak_rss: usize) {
+    println!(
+        "{}(peak memory: ~{}){}",
+        ANSI_ITALIC,
+        alloc::format_bytes(peak_rss),
+        ANSI_RESET
+    );
+}
+
+pub fn print_part(result: &PartResult, format: Format) {
+    match format {
+        Format::Text => {
+            println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+            match &result.answer {
+                Some(answer) => {
+                    println!(
+                        "{} {}{}",
+                        answer,
+                        verdict_label(&result.verdict),
+                        timing_label(result.elapsed, &result.stats, &result.alloc)
+                    );
+                }
+                None if result.timed_out => {
+                    println!("timed out after {:.2?}.", result.elapsed)
+                }
+                None => match &result.error {
+                    Some(error) if result.panicked => eprintln!(
+                        "{}Panic in day {:02}, part {}:{} {}",
+                        ANSI_BOLD, result.day, result.part, ANSI_RESET, error
+                    ),
+                    Some(error) => eprintln!(
+                        "{}Error in day {:02}, part {}:{} {}",
+                        ANSI_BOLD, result.day, result.part, ANSI_RESET, error
+                    ),
+                    None => println!("not solved."),
+                },
+            }
+        }
+        Format::Json => println!("{}", json_record(result)),
+    }
+}
+
+fn json_bench(stats: &Option<Stats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            ",\"bench\":{{\"samples\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{},\"mild_outliers\":{},\"severe_outliers\":{}}}",
+            stats.samples,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos(),
+            stats.mild_outliers,
+            stats.severe_outliers
+        ),
+        None => String::new(),
+    }
+}
+
+fn json_memory(peak_rss: Option<usize>) -> String {
+    match peak_rss {
+        Some(peak_rss) => format!(",\"peak_rss_bytes\":{}", peak_rss),
+        None => String::new(),
+    }
+}
+
+fn json_alloc(alloc: &Option<AllocStats>) -> String {
+    match alloc {
+        Some(alloc) => format!(
+            ",\"alloc\":{{\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}}}",
+            alloc.allocations, alloc.bytes, alloc.peak_bytes
+        ),
+        None => String::new(),
+    }
+}
+
+/// Serializes the parsing stage of a day as a single-line JSON object.
+pub fn json_parse_record(result: &ParseResult) -> String {
+    format!(
+        "{{\"day\":{},\"stage\":\"parse\",\"duration_ns\":{}{}{}{}}}",
+        result.day,
+        result.elapsed.as_nanos(),
+        json_bench(&result.stats),
+        json_alloc(&result.alloc),
+        json_memory(result.peak_rss)
+    )
+}
+
+/// Serializes a part result as a single-line JSON object.
+pub fn json_record(result: &PartResult) -> String {
+    let answer = match &result.answer {
+        Some(answer) => json_answer(answer),
+        None => "null".to_string(),
+    };
+
+    let verdict = match &result.verdict {
+        Some(Verdict::Correct) => "\"correct\"".to_string(),
+        Some(Verdict::Wrong { expected }) => {
+            format!("\"wrong\",\"expected\":{}", json_answer(expected))
+        }
+        Some(Verdict::Unknown) => "\"unknown\"".to_string(),
+        None => "null".to_string(),
+    };
+
+    let error = match &result.error {
+        Some(error) => json_string(error),
+        None => "null".to_string(),
+    };
+
+    let panicked = match result.panicked {
+        true => ",\"panicked\":true",
+        false => "",
+    };
+
+    let timed_out = match result.timed_out {
+        true => ",\"timed_out\":true",
+        false => "",
+    };
+
+    format!(
+        "{{\"day\":{},\"part\":{},\"solved\":{},\"answer\":{},\"verdict\":{},\"error\":{},\"duration_ns\":{}{}{}{}{}{}}}",
+        result.day,
+        result.part,
+        result.answer.is_some(),
+        answer,
+        verdict,
+        error,
+        result.elapsed.as_nanos(),
+        panicked,
+        timed_out,
+        json_bench(&result.stats),
+        json_alloc(&result.alloc),
+        json_memory(result.peak_rss)
+    )
+}
+
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(s) => json_string(s),
+    }
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Runs a day in a child process. If the day doesn't finish within `timeout`, the child is
+/// killed and the remaining parts are reported as timed out. If the input can't be read or the
+/// child can't be started, every part fails with that error.
+fn run_day(solution: &dyn Solution, options: &Options, timeout: Option<Duration>) -> DayResult {
+    let (year, day) = (solution.year(), solution.day());
+    let failed = |error: String| DayResult {
+        parts: Some(
+            options
+                .parts()
+                .into_iter()
+                .map(|part| PartResult {
+                    error: Some(error.clone()),
+                    ..PartResult::unsolved(day, part)
+                })
+                .collect(),
+        ),
+        ..DayResult::unsolved(day)
+    };
+    let input = match crate::read_input(year, day, &options.input) {
+        Ok(input) => input,
+        Err(e) => return failed(e.to_string()),
+    };
+
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut worker = match worker::spawn(year, day, &input, options) {
+        Ok(worker) => worker,
+        Err(e) => return failed(format!("could not start the day: {}", e)),
+    };
+
+    let mut parse = None;
+    let mut receive = || loop {
+        let received = match deadline {
+            Some(deadline) => worker
+                .stages
+                .recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => worker
+                .stages
+                .recv()
+                .map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(Ok(StageResult::Parse(result))) => parse = Some(result),
+            Ok(Ok(StageResult::Part(result))) => return Ok(Ok(result)),
+            Ok(Err(e)) => return Ok(Err(e)),
+            Err(e) => return Err(e),
+        }
+    };
+
+    let results: Vec<PartResult> = options
+        .parts()
+        .into_iter()
+        .map(|part| match receive() {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => PartResult {
+                error: Some(e),
+                ..PartResult::unsolved(day, part)
+            },
+            Err(RecvTimeoutError::Timeout) => PartResult {
+                elapsed: timeout.unwrap_or_default(),
+                timed_out: true,
+                ..PartResult::unsolved(day, part)
+            },
+            Err(RecvTimeoutError::Disconnected) => {
+                let message = match worker.child.wait() {
+                    Ok(status) => format!("the solution exited early ({})", status),
+                    Err(e) => format!("the solution exited early: {}", e),
+                };
+                PartResult::panicked(day, part, message)
+            }
+        })
+        .collect();
+
+    if results.iter().any(|result| result.timed_out) {
+        // the child may have finished just after the deadline, then there is nothing to kill.
+        let _ = worker.child.kill();
+    }
+    // reaps the child, its exit status doesn't matter once every part is reported.
+    let _ = worker.child.wait();
+    let stderr = worker.stderr.join().unwrap_or_default();
+
+    let peak_rss = results
+        .iter()
+        .map(|result| result.peak_rss)
+        .chain(parse.iter().map(|parse| parse.peak_rss))
+        .max()
+        .flatten();
+    DayResult {
+        day,
+        parse,
+        parts: Some(results),
+        peak_rss,
+        stderr,
+    }
+}
+
+/// The results of a day in `cargo all`. `parts` is `None` if the day has no solution,
+/// and only contains the parts selected with `--part` otherwise.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: u8,
+    /// Only set for two-stage solutions.
+    pub parse: Option<ParseResult>,
+    pub parts: Option<Vec<PartResult>>,
+    /// The highest peak memory of its stages, in bytes. Only set on Linux.
+    pub peak_rss: Option<usize>,
+    /// What the solution wrote to stderr, e.g. with `dbg!` or `eprintln!`.
+    pub stderr: String,
+}
+
+impl DayResult {
+    /// A day without a solution.
+    pub fn unsolved(day: u8) -> Self {
+        Self {
+            day,
+            parse: None,
+            parts: None,
+            peak_rss: None,
+            stderr: String::new(),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<&PartResult> {
+        self.parts
+            .as_ref()?
+            .iter()
+            .find(|result| result.part == part)
+    }
+}
+
+/// Options that only apply to `cargo all`.
+#[derive(Debug, Clone)]
+pub struct AllOptions {
+    /// Number of days that run at the same time.
+    pub jobs: usize,
+    pub year: u16,
+    /// Time limit for both parts of a day. Days can run forever if not set.
+    pub timeout: Option<Duration>,
+    /// Time limits of single days, replacing `timeout`.
+    pub day_timeouts: BTreeMap<u8, Duration>,
+    /// The days that run, in order.
+    pub days: Vec<u8>,
+    /// Leave out days without a solution instead of reporting them as not solved.
+    pub only_solved: bool,
+}
+
+impl AllOptions {
+    pub fn timeout(&self, day: u8) -> Option<Duration> {
+        self.day_timeouts.get(&day).copied().or(self.timeout)
+    }
+}
+
+impl Default for AllOptions {
+    fn default() -> Self {
+        Self {
+            jobs: 1,
+            year: crate::default_year(),
+            timeout: None,
+            day_timeouts: BTreeMap::new(),
+            days: (1..=25).collect(),
+            only_solved: false,
+        }
+    }
+}
+
+/// Runs every registered solution of a year, each day in its own child process. With `jobs > 1`, days run in parallel,
+/// but their reports are still printed in day order.
+pub fn run_all(
+    solutions: &[&'static dyn Solution],
+    options: &Options,
+    all_options: &AllOptions,
+) -> Vec<DayResult> {
+    let format = options.format;
+    let jobs = all_options.jobs.max(1);
+    let year = all_options.year;
+    let mut answers = load_answers(year);
+    let mut total = Duration::ZERO;
+    let mut day_results = Vec::new();
+
+    if format == Format::Text && jobs > 1 {
+        println!(
+            "{}Running {} days at a time, timings may be skewed.{}",
+            ANSI_ITALIC, jobs, ANSI_RESET
+        );
+    }
+
+    let days = &all_options.days;
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::SeqCst)) {
+                    let result = solutions
+                        .iter()
+                        .find(|solution| solution.year() == year && solution.day() == day)
+                        .map(|solution| run_day(*solution, options, all_options.timeout(day)))
+                        .unwrap_or_else(|| DayResult::unsolved(day));
+                    if sender.send(result).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // buffer out-of-order days until all days before them were printed.
+        let mut pending = BTreeMap::new();
+        let mut printed = 0;
+        for result in receiver {
+            pending.insert(result.day, result);
+            while let Some(mut result) = days.get(printed).and_then(|day| pending.remove(day)) {
+                let day = days[printed];
+                printed += 1;
+
+                if result.parts.is_none() && all_options.only_solved {
+                    continue;
+                }
+
+                if format == Format::Text {
+                    println!("----------");
+                    println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+                    println!("----------");
+                }
+                // printed where it was written, before the stages it came from.
+                eprint!("{}", result.stderr);
+
+                if let Some(parse) = &result.parse {
+                    print_parse(parse, format);
+                    total += parse.elapsed;
+                }
+
+                match &mut result.parts {
+                    Some(results) => {
+                        for result in results {
+                            if options.input == Input::Puzzle {
+                                check_part(result, &mut answers, options.record);
+                            }
+                            print_part(result, format);
+                            if result.answer.is_some() {
+                                total += result.elapsed;
+                            }
+                        }
+                    }
+                    None => match format {
+                        Format::Text => println!("Not solved."),
+                        Format::Json => {
+                            for part in options.parts() {
+                                print_part(&PartResult::unsolved(day, part), format);
+                            }
+                        }
+                    },
+                }
+                if let (Some(peak_rss), Format::Text) = (result.peak_rss, format) {
+                    print_memory(peak_rss);
+                }
+                day_results.push(result);
+            }
+        }
+    });
+
+    if format == Format::Text {
+        let label = match options.bench {
+            Some(_) => "Total (sum of medians):",
+            None => "Total:",
+        };
+        println!(
+            "{}{}{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            label,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
+
+    if options.record {
+        save_answers(year, &answers);
+        if format == Format::Text {
+            println!(
+                "📝 Recorded answers in \"{}\".",
+                answers_path(year).display()
+            );
+        }
+    }
+
+    day_results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_record() {
+        let result = PartResult {
+            day: 1,
+            part: 2,
+            answer: Some(Answer::Number(45000)),
+            elapsed: Duration::from_nanos(1450),
+            stats: None,
+            alloc: None,
+            peak_rss: None,
+            verdict: None,
+            error: None,
+            panicked: false,
+            timed_out: false,
+        };
+        assert_eq!(
+            json_record(&result),
+            r#"{"day":1,"part":2,"solved":true,"answer":45000,"verdict":null,"error":null,"duration_ns":1450}"#
+        );
+
+        let result = PartResult {
+            day: 10,
+            part: 2,
+            answer: Some(Answer::Text("#.\n\"x\"".to_string())),
+            elapsed: Duration::from_micros(3),
+            stats: None,
+            alloc: None,
+            peak_rss: None,
+            verdict: None,
+            error: None,
+            panicked: false,
+            timed_out: false,
+        };
+        assert_eq!(
+            json_record(&result),
+            r##"{"day":10,"part":2,"solved":true,"answer":"#.\n\"x\"","verdict":null,"error":null,"duration_ns":3000}"##
+        );
+
+        let result = PartResult {
+            day: 3,
+            part: 1,
+            answer: None,
+            elapsed: Duration::ZERO,
+            stats: None,
+            alloc: None,
+            peak_rss: None,
+            verdict: None,
+            error: None,
+            panicked: false,
+            timed_out: false,
+        };
+        assert_eq!(
+            json_record(&result),
+            r#"{"day":3,"part":1,"solved":false,"answer":null,"verdict":null,"error":null,"duration_ns":0}"#
+        );
+
+        let result = PartResult {
+            day: 1,
+            part: 1,
+            answer: Some(Answer::Number(24000)),
+            elapsed: Duration::from_nanos(200),
+            stats: Some(Stats {
+                samples: 10,
+                min: Duration::from_nanos(100),
+                median: Duration::from_nanos(200),
+                mean: Duration::from_nanos(250),
+                stddev: Duration::from_nanos(50),
+                mild_outliers: 1,
+                severe_outliers: 0,
+            }),
+            alloc: Some(AllocStats {
+                allocations: 3,
+                bytes: 4096,
+                peak_bytes: 2048,
+            }),
+            peak_rss: Some(5242880),
+            verdict: Some(Verdict::Correct),
+            error: None,
+            panicked: false,
+            timed_out: false,
+        };
+        assert_eq!(
+            json_record(&result),
+            r#"{"day":1,"part":1,"solved":true,"answer":24000,"verdict":"correct","error":null,"duration_ns":200,"bench":{"samples":10,"min_ns":100,"median_ns":200,"mean_ns":250,"stddev_ns":50,"mild_outliers":1,"severe_outliers":0},"alloc":{"allocations":3,"bytes":4096,"peak_bytes":2048},"peak_rss_bytes":5242880}"#
+        );
+
+        let result = PartResult {
+            day: 5,
+            part: 1,
+            answer: Some(Answer::Text("CMZ".to_string())),
+            elapsed: Duration::from_nanos(10),
+            stats: None,
+            alloc: None,
+            peak_rss: None,
+            verdict: Some(Verdict::Wrong {
+                expected: Answer::Text("MCD".to_string()),
+            }),
+            error: None,
+            panicked: false,
+            timed_out: false,
+        };
+        assert_eq!(
+            json_record(&result),
+            r#"{"day":5,"part":1,"solved":true,"answer":"CMZ","verdict":"wrong","expected":"MCD","error":null,"duration_ns":10}"#
+        );
+
+        let result = PartResult {
+            error: Some("invalid input".to_string()),
+            ..PartResult::unsolved(11, 2)
+        };
+        assert_eq!(
+            json_record(&result),
+            r#"{"day":11,"part":2,"solved":false,"answer":null,"verdict":null,"error":"invalid input","duration_ns":0}"#
+        );
+
+        let result = PartResult {
+            elapsed: Duration::from_secs(10),
+            timed_out: true,
+            ..PartResult::unsolved(12, 1)
+        };
+        assert_eq!(
+            json_record(&result),
+            r#"{"day":12,"part":1,"solved":false,"answer":null,"verdict":null,"error":null,"duration_ns":10000000000,"timed_out":true}"#
+        );
+    }
+
+    #[test]
+    fn test_json_parse_record() {
+        let result = ParseResult {
+            day: 8,
+            elapsed: Duration::from_nanos(1500),
+            stats: None,
+            alloc: None,
+            peak_rss: Some(2383872),
+        };
+        assert_eq!(
+            json_parse_record(&result),
+            r#"{"day":8,"stage":"parse","duration_ns":1500,"peak_rss_bytes":2383872}"#
+        );
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 1), Ok(1));
+        let message = catch_panic(|| -> u8 { panic!("invalid move {}", 3) }).unwrap_err();
+        assert!(message.starts_with("invalid move 3 at src/template/runner.rs:"));
+
+        let result = PartResult::panicked(5, 1, message.clone());
+        assert_eq!(result.failure(), Some(Failure::Panic(message)));
+        assert!(json_record(&result).ends_with(r#""duration_ns":0,"panicked":true}"#));
+    }
+}
@@ -0,0 +1,131 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+
+/// The answer to a puzzle part, as seen by the all-days runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Number(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Return types accepted for `part_one` and `part_two`: `Option<T>`, `Result<T, E>` and
+/// `Result<Option<T>, E>`, where `T` converts into an [`Answer`] and `E` implements `Display`.
+/// `None` means that the part is not solved yet, `Err` that the solution failed.
+pub trait PartOutput {
+    fn into_answer(self) -> Result<Option<Answer>, String>;
+}
+
+impl<T: Into<Answer>> PartOutput for Option<T> {
+    fn into_answer(self) -> Result<Option<Answer>, String> {
+        Ok(self.map(Into::into))
+    }
+}
+
+impl<T: Into<Answer>, E: Display> PartOutput for Result<T, E> {
+    fn into_answer(self) -> Result<Option<Answer>, String> {
+        self.map(|answer| Some(answer.into()))
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl<T: Into<Answer>, E: Display> PartOutput for Result<Option<T>, E> {
+    fn into_answer(self) -> Result<Option<Answer>, String> {
+        self.map(|answer| answer.map(Into::into))
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Receives the stages of a day from [`Solution::run`], so that each of them can be timed.
+pub trait Stages {
+    /// Parses the input once, before both parts.
+    fn parse(&mut self, func: &mut dyn FnMut());
+    fn part(&mut self, part: u8, func: &mut dyn FnMut() -> Result<Option<Answer>, String>);
+}
+
+/// A day's solution. Implemented for every `src/<year>/NN.rs` by the `solution!` macro,
+/// so that the all-days runner can call it in-process.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn part_one(&self, input: &str) -> Result<Option<Answer>, String>;
+    fn part_two(&self, input: &str) -> Result<Option<Answer>, String>;
+
+    /// Runs every stage of the day. Two-stage solutions parse the input only once.
+    fn run(&self, input: &str, stages: &mut dyn Stages) {
+        stages.part(1, &mut || self.part_one(input));
+        stages.part(2, &mut || self.part_two(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_output() {
+        assert_eq!(
+            Some(24000_u32).into_answer(),
+            Ok(Some(Answer::Number(24000)))
+        );
+        assert_eq!(None::<u32>.into_answer(), Ok(None));
+        assert_eq!(
+            Ok::<_, String>("CMZ".to_string()).into_answer(),
+            Ok(Some(Answer::Text("CMZ".to_string())))
+        );
+        assert_eq!(Ok::<Option<i32>, String>(None).into_answer(), Ok(None));
+        assert_eq!(
+            Err::<u32, _>("invalid input").into_answer(),
+            Err("invalid input".to_string())
+        );
+    }
+}
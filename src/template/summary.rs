@@ -0,0 +1,195 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! The summary printed at the end of `cargo all`, which also decides its exit status.
+use crate::template::runner::{DayResult, Failure};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Number of lines of a failed day's stderr that the summary repeats.
+const STDERR_LINES: usize = 10;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Summary {
+    /// Parts with an answer that is not known to be wrong.
+    pub solved: usize,
+    pub unsolved: usize,
+    /// Failed parts by day and part, in day order.
+    pub failures: Vec<(u8, u8, Failure)>,
+    /// What the days with a failure wrote to stderr, if anything.
+    pub stderr: Vec<(u8, String)>,
+}
+
+impl Summary {
+    /// Sums up the parts of every day that ran. Days without a solution are left out.
+    pub fn new(results: &[DayResult]) -> Self {
+        let mut summary = Summary {
+            solved: 0,
+            unsolved: 0,
+            failures: Vec::new(),
+            stderr: Vec::new(),
+        };
+
+        for result in results {
+            let failures = summary.failures.len();
+            for part in result.parts.iter().flatten() {
+                match part.failure() {
+                    Some(failure) => summary.failures.push((part.day, part.part, failure)),
+                    None if part.answer.is_some() => summary.solved += 1,
+                    None => summary.unsolved += 1,
+                }
+            }
+            if summary.failures.len() > failures && !result.stderr.trim().is_empty() {
+                summary.stderr.push((result.day, result.stderr.clone()));
+            }
+        }
+
+        summary
+    }
+
+    pub fn failed(&self) -> bool {
+        !self.failures.is_empty()
+    }
+}
+
+pub(crate) fn icon(failure: &Failure) -> &'static str {
+    match failure {
+        Failure::Panic(_) => "💥",
+        Failure::Error(_) => "⚠️",
+        Failure::Wrong { .. } => "❌",
+        Failure::Timeout(_) => "⏱️",
+    }
+}
+
+pub fn print_summary(summary: &Summary) {
+    println!(
+        "{}Summary:{} {} solved, {} not solved, {} failed",
+        ANSI_BOLD,
+        ANSI_RESET,
+        summary.solved,
+        summary.unsolved,
+        summary.failures.len()
+    );
+
+    for (day, part, failure) in &summary.failures {
+        println!(
+            "{} Day {:02}, part {}: {}",
+            icon(failure),
+            day,
+            part,
+            failure
+        );
+    }
+
+    for (day, stderr) in &summary.stderr {
+        let lines: Vec<&str> = stderr.trim_end().lines().collect();
+        let skipped = lines.len().saturating_sub(STDERR_LINES);
+        match skipped {
+            0 => println!("{}stderr of day {:02}:{}", ANSI_BOLD, day, ANSI_RESET),
+            _ => println!(
+                "{}stderr of day {:02}{} {}(last {} of {} lines){}:",
+                ANSI_BOLD,
+                day,
+                ANSI_RESET,
+                ANSI_ITALIC,
+                STDERR_LINES,
+                lines.len(),
+                ANSI_RESET
+            ),
+        }
+        for line in &lines[skipped..] {
+            println!("    {}", line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::answers::Verdict;
+    use crate::template::runner::PartResult;
+    use crate::template::Answer;
+    use std::time::Duration;
+
+    #[test]
+    fn test_summary() {
+        let results = [
+            DayResult {
+                day: 5,
+                parse: None,
+                parts: Some(vec![
+                    PartResult {
+                        answer: Some(Answer::Text("CMZ".to_string())),
+                        verdict: Some(Verdict::Wrong {
+                            expected: Answer::Text("MCD".to_string()),
+                        }),
+                        ..PartResult::unsolved(5, 1)
+                    },
+                    PartResult::panicked(5, 2, "oops at src/2022/05.rs:1:1".to_string()),
+                ]),
+                peak_rss: None,
+                stderr: "[src/2022/05.rs:12] stacks = 3\n".to_string(),
+            },
+            DayResult {
+                day: 6,
+                parse: None,
+                parts: None,
+                peak_rss: None,
+                stderr: String::new(),
+            },
+            DayResult {
+                day: 7,
+                parse: None,
+                parts: Some(vec![
+                    PartResult {
+                        answer: Some(Answer::Number(95437)),
+                        verdict: Some(Verdict::Correct),
+                        ..PartResult::unsolved(7, 1)
+                    },
+                    PartResult::unsolved(7, 2),
+                ]),
+                peak_rss: None,
+                stderr: "only shown with its day\n".to_string(),
+            },
+            DayResult {
+                day: 11,
+                parse: None,
+                parts: Some(vec![
+                    PartResult {
+                        error: Some("invalid input".to_string()),
+                        ..PartResult::unsolved(11, 1)
+                    },
+                    PartResult {
+                        elapsed: Duration::from_secs(1),
+                        timed_out: true,
+                        ..PartResult::unsolved(11, 2)
+                    },
+                ]),
+                peak_rss: None,
+                stderr: String::new(),
+            },
+        ];
+
+        let summary = Summary::new(&results);
+        assert_eq!(summary.solved, 1);
+        assert_eq!(summary.unsolved, 1);
+        assert_eq!(
+            summary
+                .failures
+                .iter()
+                .map(|(day, part, failure)| format!("{} {} {}", day, part, failure))
+                .collect::<Vec<_>>(),
+            [
+                "5 1 wrong answer CMZ, expected MCD",
+                "5 2 panicked: oops at src/2022/05.rs:1:1",
+                "11 1 error: invalid input",
+                "11 2 timed out after 1.00s",
+            ]
+        );
+        assert_eq!(
+            summary.stderr,
+            [(5, "[src/2022/05.rs:12] stacks = 3\n".to_string())]
+        );
+        assert!(summary.failed());
+    }
+}
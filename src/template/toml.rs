@@ -0,0 +1,248 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! A reader and writer for the small subset of TOML used by the template's own files:
+//! `[table]` headers, `key = value` pairs, `#` comments and integer, boolean, basic string
+//! or multi-line literal string (`'''`) values.
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Integer(i128),
+    Boolean(bool),
+    String(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Integer(n) => write!(f, "{}", n),
+            Value::Boolean(b) => write!(f, "{}", b),
+            Value::String(s) => write!(f, "{}", escape(s)),
+        }
+    }
+}
+
+/// Key-value pairs of a single table.
+pub type Table = BTreeMap<String, Value>;
+
+/// Tables by name. Keys before the first header live in the table named `""`.
+pub type Document = BTreeMap<String, Table>;
+
+pub fn parse(input: &str) -> Result<Document, String> {
+    let mut document = Document::new();
+    let mut current = String::new();
+    let mut lines = input.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = |message: &str| format!("line {}: {}", index + 1, message);
+
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .ok_or_else(|| error("unterminated table header"))?;
+            current = name.trim().to_string();
+            document.entry(current.clone()).or_default();
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `key = value`"))?;
+        let value = match value.trim().strip_prefix("'''") {
+            Some(rest) => parse_multiline_literal(rest, &mut lines).map_err(|e| error(&e))?,
+            None => parse_value(value.trim()).map_err(|e| error(&e))?,
+        };
+
+        document
+            .entry(current.clone())
+            .or_default()
+            .insert(key.trim().to_string(), value);
+    }
+
+    Ok(document)
+}
+
+fn parse_value(value: &str) -> Result<Value, String> {
+    if let Some(rest) = value.strip_prefix('"') {
+        return parse_string(rest);
+    }
+
+    // strip trailing comments of non-string values.
+    let value = value.split('#').next().unwrap_or_default().trim();
+    match value {
+        "true" => Ok(Value::Boolean(true)),
+        "false" => Ok(Value::Boolean(false)),
+        _ => value
+            .replace('_', "")
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("unsupported value `{}`", value)),
+    }
+}
+
+/// Parses a `'''` string that may span several lines, taking them from `lines`.
+/// Its contents are taken as they are, without escapes. Like in TOML, a newline right after
+/// the opening quotes is not part of the string.
+fn parse_multiline_literal<'a>(
+    rest: &'a str,
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<Value, String> {
+    let mut out = String::new();
+    let mut line = rest;
+    let mut first = true;
+
+    loop {
+        if let Some((contents, trailing)) = line.split_once("'''") {
+            out += contents;
+            let trailing = trailing.trim();
+            if !trailing.is_empty() && !trailing.starts_with('#') {
+                return Err(format!("unexpected `{}` after string", trailing));
+            }
+            return Ok(Value::String(out));
+        }
+        if !(first && line.is_empty()) {
+            out += line;
+            out.push('\n');
+        }
+        first = false;
+        line = match lines.next() {
+            Some((_, line)) => line,
+            None => return Err("unterminated string".to_string()),
+        };
+    }
+}
+
+fn parse_string(rest: &str) -> Result<Value, String> {
+    let mut out = String::new();
+    let mut chars = rest.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let trailing = chars.as_str().trim();
+                if !trailing.is_empty() && !trailing.starts_with('#') {
+                    return Err(format!("unexpected `{}` after string", trailing));
+                }
+                return Ok(Value::String(out));
+            }
+            '\\' => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some('r') => out.push('\r'),
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                other => return Err(format!("unsupported escape `\\{}`", other.unwrap_or(' '))),
+            },
+            c => out.push(c),
+        }
+    }
+
+    Err("unterminated string".to_string())
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Serializes a document, root keys first, then every table in name order.
+pub fn to_string(document: &Document) -> String {
+    let mut out = String::new();
+
+    for (name, table) in document {
+        if !name.is_empty() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out += &format!("[{}]\n", name);
+        }
+        for (key, value) in table {
+            out += &format!("{} = {}\n", key, value);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let document = parse(
+            "# comment\nyear = 2022\n\n[day01]\npart_one = 24000 # inline\npart_two = \"a\\n\\\"b\\\"\"\n\n[flags]\nenabled = true\n",
+        )
+        .unwrap();
+
+        assert_eq!(document[""]["year"], Value::Integer(2022));
+        assert_eq!(document["day01"]["part_one"], Value::Integer(24000));
+        assert_eq!(
+            document["day01"]["part_two"],
+            Value::String("a\n\"b\"".to_string())
+        );
+        assert_eq!(document["flags"]["enabled"], Value::Boolean(true));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("[day01\n"),
+            Err("line 1: unterminated table header".to_string())
+        );
+        assert_eq!(
+            parse("\nkey = \"open\n"),
+            Err("line 2: unterminated string".to_string())
+        );
+        assert_eq!(
+            parse("key = [1, 2]\n"),
+            Err("line 1: unsupported value `[1, 2]`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_multiline_literal() {
+        let document = parse(
+            "[small]\ninput = '''\n  #.\\\n\n.#\n'''\n\n[inline]\ninput = '''a\nb''' # comment\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            document["small"]["input"],
+            Value::String("  #.\\\n\n.#\n".to_string())
+        );
+        assert_eq!(
+            document["inline"]["input"],
+            Value::String("a\nb".to_string())
+        );
+        assert_eq!(
+            parse("input = '''\nopen\n"),
+            Err("line 1: unterminated string".to_string())
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = "year = 2022\n\n[day05]\npart_one = \"CMZ\"\npart_two = \"#.\\n.#\"\n\n[day11]\npart_one = 10605\n";
+        assert_eq!(to_string(&parse(input).unwrap()), input);
+    }
+}
@@ -0,0 +1,330 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Runs the days of `cargo all` in child processes, so a day that times out can be stopped.
+//! The child is the same binary, started with [`RUN_DAY_FLAG`]. It reads the input from its
+//! standard input and prints every finished stage as a single line starting with [`MARKER`],
+//! with the `key = value` pairs of the stage separated by tabs.
+use crate::template::alloc::AllocStats;
+use crate::template::args::Options;
+use crate::template::bench::Stats;
+use crate::template::runner::{ParseResult, PartResult, StageResult, Timer};
+use crate::template::toml::{self, Document, Table, Value};
+use crate::template::{Answer, Solution};
+use std::env;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::{self, Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Hidden flag of `cargo all` that runs a single day and prints its stages, e.g. `--run-day 7`.
+pub const RUN_DAY_FLAG: &str = "--run-day";
+
+/// Start of the lines with stage results, other output of the solution is passed through.
+const MARKER: &str = "\u{1e}aoc-stage\t";
+
+/// A day running in a child process.
+pub(crate) struct Worker {
+    pub(crate) child: Child,
+    /// Every finished stage, or an error if the child printed a stage that can't be read.
+    pub(crate) stages: Receiver<Result<StageResult, String>>,
+    /// Everything the child writes to stderr, available once it exited.
+    pub(crate) stderr: JoinHandle<String>,
+}
+
+/// Starts a child process that runs `day` of `year` on `input`.
+/// Only the `--bench` and `--part` options are passed on, answers are checked by the caller.
+pub(crate) fn spawn(year: u16, day: u8, input: &str, options: &Options) -> io::Result<Worker> {
+    let mut command = Command::new(env::current_exe()?);
+    command
+        .args([RUN_DAY_FLAG, &day.to_string(), "--year", &year.to_string()])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(iterations) = options.bench {
+        command.args(["--bench", &iterations.to_string()]);
+    }
+    if let Some(part) = options.part {
+        command.args(["--part", &part.to_string()]);
+    }
+
+    let mut child = command.spawn()?;
+    // the child reads its whole input before printing anything, so this can't block forever.
+    if let Some(mut stdin) = child.stdin.take() {
+        // a child that exits early is reported when its stages are missing.
+        let _ = stdin.write_all(input.as_bytes());
+    }
+
+    let stdout = child.stdout.take().expect("stdout of the child is piped");
+    let (sender, stages) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).split(b'\n') {
+            let Ok(line) = line else { break };
+            let line = String::from_utf8_lossy(&line);
+            match line.strip_prefix(MARKER) {
+                Some(record) => {
+                    // the receiver is gone after a timeout, the remaining stages are dropped.
+                    if sender.send(decode(record)).is_err() {
+                        break;
+                    }
+                }
+                None => println!("{}", line),
+            }
+        }
+    });
+
+    let mut stderr = child.stderr.take().expect("stderr of the child is piped");
+    let stderr = thread::spawn(move || {
+        let mut output = Vec::new();
+        // whatever was read before an error is still worth showing.
+        let _ = stderr.read_to_end(&mut output);
+        String::from_utf8_lossy(&output).into_owned()
+    });
+
+    Ok(Worker {
+        child,
+        stages,
+        stderr,
+    })
+}
+
+/// Runs a single day in the child process started by [`spawn`] and prints its stages.
+pub fn run(solutions: &[&'static dyn Solution], year: u16, day: u8, options: &Options) {
+    let solution = match solutions
+        .iter()
+        .find(|solution| solution.year() == year && solution.day() == day)
+    {
+        Some(solution) => solution,
+        None => {
+            eprintln!("There is no solution for day {:02} of {}.", day, year);
+            process::exit(1);
+        }
+    };
+
+    let mut input = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Failed to read the input: {}", e);
+        process::exit(1);
+    }
+
+    let on_result = |result| println!("{}{}", MARKER, encode(&result));
+    solution.run(&input, &mut Timer::new(day, options, on_result));
+}
+
+/// Serializes a stage as tab-separated `key = value` pairs. Strings are escaped, so the
+/// stage fits on a single line. The parse stage is part 0.
+fn encode(result: &StageResult) -> String {
+    let mut table = Table::new();
+    let mut insert = |key: &str, value: Value| table.insert(key.to_string(), value);
+
+    let (day, part, elapsed, stats, alloc, peak_rss) = match result {
+        StageResult::Parse(result) => (
+            result.day,
+            0,
+            result.elapsed,
+            result.stats,
+            result.alloc,
+            result.peak_rss,
+        ),
+        StageResult::Part(result) => (
+            result.day,
+            result.part,
+            result.elapsed,
+            result.stats,
+            result.alloc,
+            result.peak_rss,
+        ),
+    };
+    insert("day", Value::Integer(day.into()));
+    insert("part", Value::Integer(part.into()));
+    insert("elapsed_ns", Value::Integer(elapsed.as_nanos() as i128));
+    if let Some(stats) = stats {
+        insert("samples", Value::Integer(stats.samples as i128));
+        insert("min_ns", Value::Integer(stats.min.as_nanos() as i128));
+        insert("median_ns", Value::Integer(stats.median.as_nanos() as i128));
+        insert("mean_ns", Value::Integer(stats.mean.as_nanos() as i128));
+        insert("stddev_ns", Value::Integer(stats.stddev.as_nanos() as i128));
+        insert("mild_outliers", Value::Integer(stats.mild_outliers as i128));
+        insert(
+            "severe_outliers",
+            Value::Integer(stats.severe_outliers as i128),
+        );
+    }
+    if let Some(alloc) = alloc {
+        insert("allocations", Value::Integer(alloc.allocations as i128));
+        insert("alloc_bytes", Value::Integer(alloc.bytes as i128));
+        insert("alloc_peak_bytes", Value::Integer(alloc.peak_bytes as i128));
+    }
+    if let Some(peak_rss) = peak_rss {
+        insert("peak_rss_bytes", Value::Integer(peak_rss as i128));
+    }
+
+    if let StageResult::Part(result) = result {
+        match &result.answer {
+            Some(Answer::Number(n)) => insert("answer", Value::Integer(*n)),
+            Some(Answer::Text(s)) => insert("answer", Value::String(s.clone())),
+            None => None,
+        };
+        if let Some(error) = &result.error {
+            insert("error", Value::String(error.clone()));
+        }
+        insert("panicked", Value::Boolean(result.panicked));
+    }
+
+    let document = Document::from([(String::new(), table)]);
+    toml::to_string(&document).trim_end().replace('\n', "\t")
+}
+
+fn integer<T: TryFrom<i128>>(table: &Table, key: &str) -> Result<Option<T>, String> {
+    match table.get(key) {
+        None => Ok(None),
+        Some(Value::Integer(n)) => T::try_from(*n)
+            .map(Some)
+            .map_err(|_| format!("`{}` is out of range", key)),
+        Some(_) => Err(format!("`{}` is not an integer", key)),
+    }
+}
+
+fn required<T: TryFrom<i128>>(table: &Table, key: &str) -> Result<T, String> {
+    integer(table, key)?.ok_or_else(|| format!("`{}` is missing", key))
+}
+
+fn nanos(table: &Table, key: &str) -> Result<Duration, String> {
+    required(table, key).map(Duration::from_nanos)
+}
+
+/// Reads a stage written by [`encode`].
+fn decode(record: &str) -> Result<StageResult, String> {
+    let document = toml::parse(&record.replace('\t', "\n"))
+        .map_err(|e| format!("invalid stage record: {}", e))?;
+    let table = document.get("").cloned().unwrap_or_default();
+
+    let day = required(&table, "day")?;
+    let part = required(&table, "part")?;
+    let elapsed = nanos(&table, "elapsed_ns")?;
+    let stats = match integer(&table, "samples")? {
+        Some(samples) => Some(Stats {
+            samples,
+            min: nanos(&table, "min_ns")?,
+            median: nanos(&table, "median_ns")?,
+            mean: nanos(&table, "mean_ns")?,
+            stddev: nanos(&table, "stddev_ns")?,
+            mild_outliers: required(&table, "mild_outliers")?,
+            severe_outliers: required(&table, "severe_outliers")?,
+        }),
+        None => None,
+    };
+    let alloc = match integer(&table, "allocations")? {
+        Some(allocations) => Some(AllocStats {
+            allocations,
+            bytes: required(&table, "alloc_bytes")?,
+            peak_bytes: required(&table, "alloc_peak_bytes")?,
+        }),
+        None => None,
+    };
+    let peak_rss = integer(&table, "peak_rss_bytes")?;
+
+    if part == 0 {
+        return Ok(StageResult::Parse(ParseResult {
+            day,
+            elapsed,
+            stats,
+            alloc,
+            peak_rss,
+        }));
+    }
+
+    let answer = match table.get("answer") {
+        Some(Value::Integer(n)) => Some(Answer::Number(*n)),
+        Some(Value::String(s)) => Some(Answer::Text(s.clone())),
+        _ => None,
+    };
+    let error = match table.get("error") {
+        Some(Value::String(error)) => Some(error.clone()),
+        _ => None,
+    };
+    Ok(StageResult::Part(PartResult {
+        answer,
+        elapsed,
+        stats,
+        alloc,
+        peak_rss,
+        error,
+        panicked: table.get("panicked") == Some(&Value::Boolean(true)),
+        ..PartResult::unsolved(day, part)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(result: StageResult) -> StageResult {
+        let record = encode(&result);
+        assert!(!record.contains('\n'));
+        decode(&record).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_part() {
+        let part = PartResult {
+            answer: Some(Answer::Text("#..#\n\"#\"\t.".to_string())),
+            elapsed: Duration::from_nanos(1_234_567),
+            stats: Some(Stats {
+                samples: 10,
+                min: Duration::from_nanos(900),
+                median: Duration::from_nanos(1_000),
+                mean: Duration::from_nanos(1_100),
+                stddev: Duration::from_nanos(50),
+                mild_outliers: 1,
+                severe_outliers: 0,
+            }),
+            alloc: Some(AllocStats {
+                allocations: 3,
+                bytes: 96,
+                peak_bytes: 64,
+            }),
+            peak_rss: Some(4096),
+            ..PartResult::unsolved(10, 2)
+        };
+        match round_trip(StageResult::Part(part.clone())) {
+            StageResult::Part(result) => assert_eq!(result, part),
+            StageResult::Parse(_) => panic!("expected a part"),
+        }
+
+        let panicked = PartResult::panicked(3, 1, "index out of bounds".to_string());
+        match round_trip(StageResult::Part(panicked.clone())) {
+            StageResult::Part(result) => assert_eq!(result, panicked),
+            StageResult::Parse(_) => panic!("expected a part"),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_parse() {
+        let parse = ParseResult {
+            day: 8,
+            elapsed: Duration::from_nanos(42),
+            stats: None,
+            alloc: None,
+            peak_rss: None,
+        };
+        match round_trip(StageResult::Parse(parse.clone())) {
+            StageResult::Parse(result) => assert_eq!(result, parse),
+            StageResult::Part(_) => panic!("expected the parse stage"),
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode("day = 1\tpart = 1").err(),
+            Some("`elapsed_ns` is missing".to_string())
+        );
+        assert_eq!(
+            decode("day = 300\tpart = 1\telapsed_ns = 0").err(),
+            Some("`day` is out of range".to_string())
+        );
+    }
+}